@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NotPrimeError;
 
 impl fmt::Display for NotPrimeError {
@@ -8,3 +8,5 @@ impl fmt::Display for NotPrimeError {
         write!(f, "The order of the field must be a prime number")
     }
 }
+
+impl std::error::Error for NotPrimeError {}
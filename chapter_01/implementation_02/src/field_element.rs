@@ -1,9 +1,87 @@
-use num_bigint::BigInt;
+use crate::errors::NotPrimeError;
 use num_bigint::ToBigInt;
+use num_bigint::{BigInt, BigUint, Sign};
+use sha2::{Digest, Sha256};
 use std::fmt;
 use std::ops;
 
-#[derive(Debug, Eq)]
+/// Number of Miller-Rabin rounds in `is_probable_prime`, which bounds the
+/// chance of accepting a composite by 4^-64.
+const MILLER_RABIN_ROUNDS: u32 = 64;
+
+/// Miller-Rabin for moduli too large for `u128`.
+///
+/// Bases are derived by hashing the candidate with the round number, so the
+/// result is reproducible while the bases can not be chosen by the caller.
+pub fn is_probable_prime(number: &BigInt) -> bool {
+    let Some(number) = number.to_biguint() else {
+        return false;
+    };
+    let one = BigUint::from(1_u32);
+    let two = BigUint::from(2_u32);
+    if number < two {
+        return false;
+    }
+    for small_prime in [2_u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if number == BigUint::from(small_prime) {
+            return true;
+        }
+        if (&number % small_prime) == BigUint::ZERO {
+            return false;
+        }
+    }
+
+    let minus_one = &number - &one;
+    let s = minus_one.trailing_zeros().unwrap_or(0);
+    let d = &minus_one >> s;
+    let (_, number_bytes) = BigInt::from(number.clone()).to_bytes_be();
+    'rounds: for round in 0..MILLER_RABIN_ROUNDS {
+        let digest = Sha256::new()
+            .chain_update(&number_bytes)
+            .chain_update(round.to_be_bytes())
+            .finalize();
+        // base in [2, number - 2]
+        let base = BigUint::from_bytes_be(&digest) % (&number - 3_u32) + &two;
+        let mut x = base.modpow(&d, &number);
+        if x == one || x == minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.modpow(&two, &number);
+            if x == minus_one {
+                continue 'rounds;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Modulus of a field only known at runtime, checked to be prime once so
+/// elements can then be built from it without repeating the test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimeModulus(BigInt);
+
+impl PrimeModulus {
+    pub fn new(prime: BigInt) -> Result<Self, NotPrimeError> {
+        if !is_probable_prime(&prime) {
+            return Err(NotPrimeError);
+        }
+        Ok(Self(prime))
+    }
+
+    pub fn value(&self) -> &BigInt {
+        &self.0
+    }
+
+    /// The element `num` of this field, with the range check of
+    /// `FieldElement::new`.
+    pub fn element(&self, num: BigInt) -> FieldElement {
+        FieldElement::new(num, self.0.clone())
+    }
+}
+
+#[derive(Debug, Clone, Eq)]
 pub struct FieldElement {
     num: BigInt,
     prime: BigInt,
@@ -17,6 +95,14 @@ impl FieldElement {
         Self { num, prime }
     }
 
+    pub fn num(&self) -> &BigInt {
+        &self.num
+    }
+
+    pub fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
     pub fn pow(&self, exponent: BigInt) -> Self {
         let positive_exponent = exponent.rem_euclid(self.prime.clone() - 1);
         let num = self.num.modpow(&positive_exponent, &self.prime);
@@ -91,6 +177,9 @@ impl ops::Div for FieldElement {
         if self.prime != rhs.prime {
             panic!("Cannot divide two numbers in different Fields");
         }
+        if rhs.num == BigInt::from(0) {
+            panic!("Cannot divide by zero-valued field element");
+        }
         // a / b == a * b.pow(p - 2)
         let exponent = self.prime.clone() - 2_i32.to_bigint().unwrap();
         let rhs_factor = rhs.num.modpow(&exponent, &self.prime);
@@ -108,7 +197,12 @@ trait RemEuclid {
 
 impl RemEuclid for BigInt {
     fn rem_euclid(&self, rhs: Self) -> Self {
-        self.modpow(&1_i32.to_bigint().unwrap(), &rhs)
+        let remainder = self % &rhs;
+        if remainder.sign() == Sign::Minus {
+            remainder + rhs
+        } else {
+            remainder
+        }
     }
 }
 
@@ -191,4 +285,79 @@ mod tests {
 
         assert_eq!(a.pow(exponent), b);
     }
+
+    /// Order of the secp256k1 field.
+    fn secp256k1_p() -> BigInt {
+        BigInt::parse_bytes(
+            b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            16,
+        )
+        .unwrap()
+    }
+
+    /// Order of the secp256k1 group.
+    fn secp256k1_n() -> BigInt {
+        BigInt::parse_bytes(
+            b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            16,
+        )
+        .unwrap()
+    }
+
+    #[should_panic(expected = "Cannot divide by zero")]
+    #[test]
+    fn divide_by_zero_panics() {
+        let prime = 19_i32.to_bigint().unwrap();
+        let a = FieldElement::new(2_i32.to_bigint().unwrap(), prime.clone());
+        let zero = FieldElement::new(0_i32.to_bigint().unwrap(), prime);
+
+        let _ = a / zero;
+    }
+
+    #[test]
+    fn probable_primes() {
+        let primes = [
+            BigInt::from(2),
+            BigInt::from(223),
+            BigInt::from(u128::MAX - 158),
+            secp256k1_p(),
+            secp256k1_n(),
+        ];
+        let composites = [
+            BigInt::from(-7),
+            BigInt::from(1),
+            BigInt::from(561),
+            BigInt::from(3_825_123_056_546_413_051_u64),
+            secp256k1_p() * secp256k1_n(),
+            secp256k1_p() + 2,
+        ];
+
+        for prime in primes {
+            assert!(is_probable_prime(&prime), "{} is prime", prime);
+        }
+        for composite in composites {
+            assert!(!is_probable_prime(&composite), "{} is composite", composite);
+        }
+    }
+
+    #[test]
+    fn prime_modulus_validates_once() {
+        let modulus = PrimeModulus::new(secp256k1_p()).unwrap();
+
+        assert_eq!(modulus.value(), &secp256k1_p());
+        assert_eq!(
+            modulus.element(BigInt::from(7)),
+            FieldElement::new(BigInt::from(7), secp256k1_p())
+        );
+        assert_eq!(PrimeModulus::new(secp256k1_p() + 2), Err(NotPrimeError));
+        assert_eq!(PrimeModulus::new(BigInt::from(10)), Err(NotPrimeError));
+    }
+
+    #[should_panic(expected = "not in field range")]
+    #[test]
+    fn prime_modulus_elements_are_range_checked() {
+        let modulus = PrimeModulus::new(BigInt::from(19)).unwrap();
+
+        let _ = modulus.element(BigInt::from(19));
+    }
 }
@@ -1 +1,2 @@
-mod field_element;
+pub mod errors;
+pub mod field_element;
@@ -1,33 +1,292 @@
-use crate::errors::NotPrimeError;
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 
-fn is_prime(number_to_check: u128) -> bool {
-    if number_to_check == 1 {
+/// Bases for which Miller-Rabin has no strong pseudoprime below
+/// `MILLER_RABIN_BOUND`.
+const MILLER_RABIN_BASES: [u128; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Smallest strong pseudoprime to all of `MILLER_RABIN_BASES`.
+const MILLER_RABIN_BOUND: u128 = 3_317_044_064_679_887_385_961_981;
+
+/// Miller-Rabin with the first 13 primes as bases, which is exact for every
+/// number below `MILLER_RABIN_BOUND`. Above that it adds a strong Lucas test,
+/// which makes it Baillie-PSW: no composite passing it is known.
+///
+/// It is a `const fn` so every field checks its order at compile time.
+pub const fn is_prime(number_to_check: u128) -> bool {
+    if number_to_check < 2 {
         return false;
     }
+    let mut i = 0;
+    while i < MILLER_RABIN_BASES.len() {
+        let base = MILLER_RABIN_BASES[i];
+        if number_to_check == base {
+            return true;
+        }
+        if number_to_check.is_multiple_of(base) {
+            return false;
+        }
+        i += 1;
+    }
 
-    let mut aux = 2;
-    while aux * aux <= number_to_check {
-        if number_to_check % aux == 0 {
+    let minus_one = number_to_check - 1;
+    let s = minus_one.trailing_zeros();
+    let d = minus_one >> s;
+    let mut i = 0;
+    while i < MILLER_RABIN_BASES.len() {
+        if !is_strong_probable_prime(number_to_check, MILLER_RABIN_BASES[i], d, s) {
             return false;
         }
-        aux += 1;
+        i += 1;
+    }
+
+    number_to_check < MILLER_RABIN_BOUND || is_strong_lucas_probable_prime(number_to_check)
+}
+
+/// Miller-Rabin round for `base` where `number - 1 == d * 2^s` with `d` odd.
+const fn is_strong_probable_prime(number: u128, base: u128, d: u128, s: u32) -> bool {
+    let minus_one = number - 1;
+    let mut x = pow_mod(base, d, number);
+    if x == 1 || x == minus_one {
+        return true;
+    }
+    let mut round = 1;
+    while round < s {
+        x = mul_mod(x, x, number);
+        if x == minus_one {
+            return true;
+        }
+        round += 1;
+    }
+    false
+}
+
+/// Strong Lucas test with Selfridge's parameters: the first D in 5, -7, 9,
+/// -11, ... with Jacobi symbol (D / number) == -1, P = 1 and Q = (1 - D) / 4.
+///
+/// `number` must be odd and have no prime factor below 43.
+const fn is_strong_lucas_probable_prime(number: u128) -> bool {
+    let root = number.isqrt();
+    if root * root == number {
+        // There is no D to pick for squares
+        return false;
+    }
+    let mut d: i128 = 5;
+    loop {
+        match jacobi(d, number) {
+            -1 => break,
+            0 if d.unsigned_abs() != number => return false,
+            _ => d = if d > 0 { -(d + 2) } else { -d + 2 },
+        }
+    }
+    let d_mod = reduce(d, number);
+    let q = reduce((1 - d) / 4, number);
+
+    // number + 1 == k * 2^s with k odd, which can not overflow as u128::MAX
+    // is a multiple of 3
+    let s = (number + 1).trailing_zeros();
+    let k = (number + 1) >> s;
+
+    // U_1 = 1, V_1 = P, then the bits of k below the leading one
+    let (mut u, mut v, mut q_k) = (1, 1, q);
+    let mut i = 127 - k.leading_zeros();
+    while i > 0 {
+        i -= 1;
+        // U_2m = U_m * V_m and V_2m = V_m^2 - 2 * Q^m
+        u = mul_mod(u, v, number);
+        v = sub_mod(mul_mod(v, v, number), add_mod(q_k, q_k, number), number);
+        q_k = mul_mod(q_k, q_k, number);
+        if (k >> i) & 1 == 1 {
+            // U_m+1 = (P * U_m + V_m) / 2 and V_m+1 = (D * U_m + P * V_m) / 2
+            (u, v) = (
+                half_mod(add_mod(u, v, number), number),
+                half_mod(add_mod(mul_mod(d_mod, u, number), v, number), number),
+            );
+            q_k = mul_mod(q_k, q, number);
+        }
+    }
+    if u == 0 || v == 0 {
+        return true;
+    }
+
+    let mut round = 1;
+    while round < s {
+        v = sub_mod(mul_mod(v, v, number), add_mod(q_k, q_k, number), number);
+        if v == 0 {
+            return true;
+        }
+        q_k = mul_mod(q_k, q_k, number);
+        round += 1;
+    }
+    false
+}
+
+/// Jacobi symbol (a / n) for odd `n`.
+const fn jacobi(a: i128, n: u128) -> i8 {
+    let mut a = reduce(a, n);
+    let mut n = n;
+    let mut result = 1;
+    while a != 0 {
+        while a.is_multiple_of(2) {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// `value / 2 % modulus` for `value < modulus` and odd `modulus`.
+const fn half_mod(value: u128, modulus: u128) -> u128 {
+    if value.is_multiple_of(2) {
+        value / 2
+    } else {
+        // (value + modulus) / 2 without overflowing
+        value / 2 + modulus / 2 + 1
+    }
+}
+
+/// Reduces a signed value into `0..modulus`.
+const fn reduce(value: i128, modulus: u128) -> u128 {
+    let remainder = value.unsigned_abs() % modulus;
+    if value < 0 && remainder != 0 {
+        modulus - remainder
+    } else {
+        remainder
+    }
+}
+
+/// `(a + b) % modulus` for `a, b < modulus` without overflowing `u128`.
+const fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a - b) % modulus` for `a, b < modulus`.
+const fn sub_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        modulus - (b - a)
+    }
+}
+
+/// Full 256-bit product of `a` and `b` as `(high, low)` halves.
+const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (middle << 64) | (low_low & MASK);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// `(a * b) % modulus` for `a, b < modulus`.
+const fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    let (high, low) = widening_mul(a, b);
+    if high == 0 {
+        return low % modulus;
+    }
+    // high < modulus because both factors are, so shift it back in one bit
+    // at a time: result = high * 2^128 + low (mod modulus).
+    let mut result = high;
+    let mut i = 128;
+    while i > 0 {
+        i -= 1;
+        result = add_mod(result, result, modulus);
+        if (low >> i) & 1 == 1 {
+            result = add_mod(result, 1, modulus);
+        }
     }
+    result
+}
 
-    true
+/// `base^exponent % modulus` by square-and-multiply.
+const fn pow_mod(base: u128, exponent: u128, modulus: u128) -> u128 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
 }
 
+/// Inverse of `value` modulo `modulus` by the extended Euclidean algorithm,
+/// keeping the Bezout coefficient reduced so it never overflows.
+fn inverse_mod(value: u128, modulus: u128) -> Option<u128> {
+    let (mut old_r, mut r) = (modulus, value);
+    let (mut old_t, mut t) = (0, 1 % modulus);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        let product = mul_mod(quotient % modulus, t, modulus);
+        (old_t, t) = (t, sub_mod(old_t, product, modulus));
+    }
+    (old_r == 1).then_some(old_t)
+}
+
+/// Element of the field of prime order `P`.
+///
+/// Every constructor evaluates `ASSERT_PRIME`, so building an element of a
+/// field whose order is not prime is a compile error.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FiniteFieldElement<const P: u128> {
-    pub value: i128,
+    value: u128,
 }
 
 impl<const P: u128> FiniteFieldElement<P> {
-    pub fn new(value: i128) -> Result<Self, NotPrimeError> {
-        if !is_prime(P) {
-            return Err(NotPrimeError);
-        }
-        Ok(Self { value })
+    const ASSERT_PRIME: () = assert!(is_prime(P), "The order of the field must be a prime number");
+
+    pub fn new(value: i128) -> Self {
+        Self::from_u128(reduce(value, P))
+    }
+
+    /// Element for any `u128` value, which `new` can not take when `P` is
+    /// above `i128::MAX`.
+    pub fn from_u128(value: u128) -> Self {
+        let () = Self::ASSERT_PRIME;
+        Self { value: value % P }
+    }
+
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+
+    /// Multiplicative inverse, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        Some(Self {
+            value: inverse_mod(self.value, P)?,
+        })
+    }
+}
+
+impl<const P: u128> From<i64> for FiniteFieldElement<P> {
+    fn from(value: i64) -> Self {
+        Self::new(value as i128)
     }
 }
 
@@ -36,7 +295,7 @@ impl<const P: u128> Add<FiniteFieldElement<P>> for FiniteFieldElement<P> {
 
     fn add(self, other_number: Self) -> Self {
         Self {
-            value: (self.value + other_number.value).rem_euclid(P as i128),
+            value: add_mod(self.value, other_number.value, P),
         }
     }
 }
@@ -46,7 +305,7 @@ impl<const P: u128> Sub<FiniteFieldElement<P>> for FiniteFieldElement<P> {
 
     fn sub(self, other_number: Self) -> Self {
         Self {
-            value: (self.value - other_number.value).rem_euclid(P as i128),
+            value: sub_mod(self.value, other_number.value, P),
         }
     }
 }
@@ -56,20 +315,25 @@ impl<const P: u128> Mul<FiniteFieldElement<P>> for FiniteFieldElement<P> {
 
     fn mul(self, other_number: Self) -> Self {
         Self {
-            value: (self.value * other_number.value).rem_euclid(P as i128),
+            value: mul_mod(self.value, other_number.value, P),
         }
     }
 }
 
 impl<const P: u128> FiniteFieldElement<P> {
+    /// Raises the element to `n`, where negative exponents go through the
+    /// inverse by Fermat's little theorem.
     pub fn pow(&self, n: i128) -> Self {
-        let exp = n.rem_euclid((P - 1_u128) as i128);
-        let mut value = self.value;
-        for _ in 1..exp {
-            value *= self.value;
+        if n >= 0 {
+            self.pow_unsigned(n as u128)
+        } else {
+            self.pow_unsigned(reduce(n, P - 1))
         }
+    }
+
+    fn pow_unsigned(&self, exponent: u128) -> Self {
         Self {
-            value: value.rem_euclid(P as i128),
+            value: pow_mod(self.value, exponent, P),
         }
     }
 }
@@ -78,89 +342,274 @@ impl<const P: u128> Div<FiniteFieldElement<P>> for FiniteFieldElement<P> {
     type Output = Self;
 
     fn div(self, other_number: Self) -> Self {
-        self * other_number.pow((P - 2_u128) as i128)
+        match other_number.inverse() {
+            Some(other_inverse) => Self {
+                value: mul_mod(self.value, other_inverse.value, P),
+            },
+            None => panic!("Cannot divide by zero-valued field element"),
+        }
     }
 }
 
 impl<const P: u128> MulAssign<FiniteFieldElement<P>> for FiniteFieldElement<P> {
     fn mul_assign(&mut self, other_number: Self) {
-        self.value = (self.value * other_number.value).rem_euclid(P as i128);
+        self.value = mul_mod(self.value, other_number.value, P);
     }
 }
 
 impl<const P: u128> AddAssign<FiniteFieldElement<P>> for FiniteFieldElement<P> {
     fn add_assign(&mut self, other_number: Self) {
-        self.value = (self.value + other_number.value).rem_euclid(P as i128);
+        self.value = add_mod(self.value, other_number.value, P);
     }
 }
 
 impl<const P: u128> SubAssign<FiniteFieldElement<P>> for FiniteFieldElement<P> {
     fn sub_assign(&mut self, other_number: Self) {
-        self.value = (self.value - other_number.value).rem_euclid(P as i128);
+        self.value = sub_mod(self.value, other_number.value, P);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[should_panic]
-    #[test]
-    fn test_can_not_create_field_with_no_prime_order() {
-        let _ = FiniteFieldElement::<10>::new(1).unwrap();
-    }
+    use num_bigint::BigUint;
 
     #[test]
     fn test_add_two_finite_field_elements() {
-        let first_field_element = FiniteFieldElement::<11>::new(1).unwrap();
-        let second_field_element = FiniteFieldElement::<11>::new(20).unwrap();
+        let first_field_element = FiniteFieldElement::<11>::new(1);
+        let second_field_element = FiniteFieldElement::<11>::new(20);
 
         assert_eq!(
             first_field_element + second_field_element,
-            FiniteFieldElement::<11>::new(10).unwrap()
+            FiniteFieldElement::<11>::new(10)
         );
     }
 
     #[test]
     fn test_sub_two_finite_field_elements() {
-        let first_field_element = FiniteFieldElement::<11>::new(1).unwrap();
-        let second_field_element = FiniteFieldElement::<11>::new(20).unwrap();
+        let first_field_element = FiniteFieldElement::<11>::new(1);
+        let second_field_element = FiniteFieldElement::<11>::new(20);
 
         assert_eq!(
             first_field_element - second_field_element,
-            FiniteFieldElement::<11>::new(3).unwrap()
+            FiniteFieldElement::<11>::new(3)
         );
     }
 
     #[test]
     fn test_mul_two_finite_field_elements() {
-        let first_field_element = FiniteFieldElement::<11>::new(1).unwrap();
-        let second_field_element = FiniteFieldElement::<11>::new(20).unwrap();
+        let first_field_element = FiniteFieldElement::<11>::new(1);
+        let second_field_element = FiniteFieldElement::<11>::new(20);
 
         assert_eq!(
             first_field_element * second_field_element,
-            FiniteFieldElement::<11>::new(9).unwrap()
+            FiniteFieldElement::<11>::new(9)
         );
     }
 
     #[test]
     fn test_pow_a_finite_field_with_a_number() {
-        let first_field_element = FiniteFieldElement::<11>::new(3).unwrap();
+        let first_field_element = FiniteFieldElement::<11>::new(3);
 
-        assert_eq!(
-            first_field_element.pow(3),
-            FiniteFieldElement::<11>::new(5).unwrap()
-        );
+        assert_eq!(first_field_element.pow(3), FiniteFieldElement::<11>::new(5));
     }
 
     #[test]
     fn test_div_two_finite_field_elements() {
-        let first_field_element = FiniteFieldElement::<11>::new(1).unwrap();
-        let second_field_element = FiniteFieldElement::<11>::new(20).unwrap();
+        let first_field_element = FiniteFieldElement::<11>::new(1);
+        let second_field_element = FiniteFieldElement::<11>::new(20);
 
         assert_eq!(
             first_field_element / second_field_element,
-            FiniteFieldElement::<11>::new(5).unwrap()
+            FiniteFieldElement::<11>::new(5)
+        );
+    }
+
+    #[test]
+    fn test_new_reduces_the_value_into_the_field() {
+        assert_eq!(
+            FiniteFieldElement::<11>::new(20),
+            FiniteFieldElement::<11>::new(9)
+        );
+        assert_eq!(
+            FiniteFieldElement::<11>::new(-1),
+            FiniteFieldElement::<11>::from(10)
+        );
+    }
+
+    // Largest primes below 2^64 and 2^128
+    const P64: u128 = 18_446_744_073_709_551_557;
+    const P128: u128 = u128::MAX - 158;
+
+    fn element<const P: u128>(value: u128) -> FiniteFieldElement<P> {
+        FiniteFieldElement::from_u128(value)
+    }
+
+    #[test]
+    fn test_widening_mul_matches_bigint() {
+        let values = [0, 1, u64::MAX as u128, P64, P128, u128::MAX];
+        for a in values {
+            for b in values {
+                let (high, low) = widening_mul(a, b);
+                let product = (BigUint::from(high) << 128) + BigUint::from(low);
+                assert_eq!(product, BigUint::from(a) * BigUint::from(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_near_the_u128_boundary() {
+        let minus_one = element::<P128>(P128 - 1);
+        let minus_two = element::<P128>(P128 - 2);
+        let one = element::<P128>(1);
+
+        assert_eq!(minus_one.clone() + minus_one.clone(), minus_two);
+        assert_eq!(one.clone() - minus_one.clone(), element::<P128>(2));
+        assert_eq!(minus_one.clone() * minus_one.clone(), one);
+        assert_eq!(minus_two.clone() * minus_one.clone(), element::<P128>(2));
+        assert_eq!(minus_one.clone() / minus_one.clone(), one);
+        assert_eq!(minus_one.pow(-1), minus_one);
+    }
+
+    #[test]
+    fn test_mul_near_the_u128_boundary_matches_bigint() {
+        let a = element::<P128>(P128 - 12_345_678_901_234_567_890);
+        let b = element::<P128>(0x1234_5678_9abc_def0_1234_5678_9abc_def0);
+        let expected = (BigUint::from(a.value) * BigUint::from(b.value)) % P128;
+
+        assert_eq!((a * b).value, u128::try_from(expected).unwrap());
+    }
+
+    #[test]
+    fn test_fermat_near_the_u64_and_u128_boundaries() {
+        let a = element::<P64>(P64 - 3);
+        assert_eq!(a.pow((P64 - 1) as i128), element::<P64>(1));
+        assert_eq!(a.clone() * a.pow(-1), element::<P64>(1));
+
+        let b = element::<P128>(P128 / 3);
+        assert_eq!(b.pow_unsigned(P128 - 1), element::<P128>(1));
+        assert_eq!(b.clone() * b.pow(-1), element::<P128>(1));
+    }
+
+    #[test]
+    fn test_pow_with_large_exponents() {
+        let base = FiniteFieldElement::<11>::new(3);
+        let expected =
+            BigUint::from(3_u32).modpow(&BigUint::from(i128::MAX as u128), &11_u32.into());
+
+        assert_eq!(BigUint::from(base.pow(i128::MAX).value), expected);
+        assert_eq!(base.pow(0), FiniteFieldElement::<11>::new(1));
+    }
+
+    #[test]
+    fn test_inverse_by_extended_euclid() {
+        for value in 1..223 {
+            let element = FiniteFieldElement::<223>::from(value);
+            assert_eq!(
+                element.clone() * element.inverse().unwrap(),
+                FiniteFieldElement::<223>::from(1)
+            );
+        }
+        assert_eq!(FiniteFieldElement::<223>::from(0).inverse(), None);
+
+        let a = element::<P128>(P128 - 3);
+        assert_eq!(a.inverse(), Some(a.pow_unsigned(P128 - 2)));
+    }
+
+    #[should_panic(expected = "Cannot divide by zero")]
+    #[test]
+    fn test_div_by_zero_panics() {
+        let _ = FiniteFieldElement::<11>::from(1) / FiniteFieldElement::<11>::from(0);
+    }
+
+    #[test]
+    fn test_is_prime_on_known_primes_and_composites() {
+        let primes = [
+            2,
+            3,
+            5,
+            41,
+            43,
+            223,
+            7919,
+            2_147_483_647,
+            P64,
+            (1 << 127) - 1,
+            P128,
+        ];
+        // Carmichael numbers, strong pseudoprimes to the smallest bases and
+        // products of two large primes.
+        let composites = [
+            0,
+            1,
+            4,
+            561,
+            2047,
+            1_373_653,
+            3_215_031_751,
+            3_825_123_056_546_413_051,
+            318_665_857_834_031_151_167_461,
+            MILLER_RABIN_BOUND,
+            4_294_967_291 * 4_294_967_279,
+            18_446_744_073_709_551_557 * 13,
+        ];
+
+        for prime in primes {
+            assert!(is_prime(prime), "{} is prime", prime);
+        }
+        for composite in composites {
+            assert!(!is_prime(composite), "{} is composite", composite);
+        }
+    }
+
+    #[test]
+    fn test_strong_lucas_test_matches_known_pseudoprimes() {
+        // The smallest strong Lucas pseudoprimes with Selfridge's parameters
+        let pseudoprimes = [5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199];
+        let composites = [
+            45,
+            47 * 53,
+            2047,
+            MILLER_RABIN_BOUND,
+            4_294_967_291 * 4_294_967_279,
+        ];
+        let primes = [47, 7919, P64, (1 << 127) - 1, P128];
+
+        for pseudoprime in pseudoprimes {
+            assert!(
+                is_strong_lucas_probable_prime(pseudoprime),
+                "{}",
+                pseudoprime
+            );
+            assert!(!is_prime(pseudoprime), "{} is composite", pseudoprime);
+        }
+        for composite in composites {
+            assert!(!is_strong_lucas_probable_prime(composite), "{}", composite);
+        }
+        for prime in primes {
+            assert!(is_strong_lucas_probable_prime(prime), "{}", prime);
+        }
+    }
+
+    #[test]
+    fn test_jacobi_symbol() {
+        assert_eq!(jacobi(5, 11), 1);
+        assert_eq!(jacobi(-7, 11), 1);
+        assert_eq!(jacobi(-7, 13), -1);
+        assert_eq!(jacobi(9, 21), 0);
+        assert_eq!(jacobi(1001, 9907), -1);
+    }
+
+    #[test]
+    fn test_new_is_fast_for_large_primes() {
+        assert_eq!(FiniteFieldElement::<P64>::new(-1).value(), P64 - 1);
+        assert_eq!(
+            FiniteFieldElement::<P128>::new(i128::MAX).value(),
+            i128::MAX as u128
+        );
+        assert_eq!(
+            FiniteFieldElement::<P128>::from_u128(u128::MAX).value(),
+            158
         );
     }
 }
@@ -1,2 +1 @@
-mod errors;
-mod finite_field_element;
+pub mod finite_field_element;
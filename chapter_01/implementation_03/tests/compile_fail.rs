@@ -0,0 +1,9 @@
+// The primality of the order is checked when an element is built, which only
+// happens on full builds, so a passing case makes trybuild build instead of
+// just type-check.
+#[test]
+fn non_prime_field_orders_do_not_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/compile_pass/*.rs");
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,5 @@
+use finite_field::finite_field_element::FiniteFieldElement;
+
+fn main() {
+    let _ = FiniteFieldElement::<10>::new(1);
+}
@@ -0,0 +1,6 @@
+use finite_field::finite_field_element::FiniteFieldElement;
+
+fn main() {
+    // 561 is a Carmichael number
+    let _ = FiniteFieldElement::<561>::from(1_i64);
+}
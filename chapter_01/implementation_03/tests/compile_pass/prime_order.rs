@@ -0,0 +1,6 @@
+use finite_field::finite_field_element::FiniteFieldElement;
+
+fn main() {
+    let element = FiniteFieldElement::<223>::new(-1);
+    assert_eq!(element.value(), 222);
+}
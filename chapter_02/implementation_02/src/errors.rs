@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Errors from building or adding curve points, shared by every point
+/// implementation in the book.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurveError {
+    /// The coordinates do not satisfy the curve equation.
+    NotOnCurve,
+    /// The operands lie on different curves.
+    CurveMismatch,
+    /// The input does not describe a point, such as a single coordinate or
+    /// a malformed SEC encoding.
+    InvalidEncoding,
+    /// The coordinate arithmetic does not fit in the coordinate type.
+    Overflow,
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CurveError::NotOnCurve => write!(f, "Point is not on the curve"),
+            CurveError::CurveMismatch => write!(f, "Points are not on the same curve"),
+            CurveError::InvalidEncoding => write!(f, "Input does not encode a point"),
+            CurveError::Overflow => write!(f, "Coordinates overflow their integer type"),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
@@ -1 +1,2 @@
-mod point;
+pub mod errors;
+pub mod point;
@@ -1,3 +1,4 @@
+use crate::errors::CurveError;
 use num_bigint::BigInt;
 use std::fmt;
 use std::ops;
@@ -12,21 +13,57 @@ pub struct Point {
 
 impl Point {
     pub fn new(x: Option<BigInt>, y: Option<BigInt>, a: BigInt, b: BigInt) -> Self {
-        match (x.clone(), y.clone()) {
+        Self::try_new(x, y, a, b).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_new(
+        x: Option<BigInt>,
+        y: Option<BigInt>,
+        a: BigInt,
+        b: BigInt,
+    ) -> Result<Self, CurveError> {
+        match (&x, &y) {
             (Some(x_num), Some(y_num)) => {
-                if y_num.pow(2) != x_num.pow(3) + a.clone() * x_num.clone() + b.clone() {
-                    panic!("({}, {}) is not on the curve", x_num, y_num);
+                if y_num.pow(2) != x_num.pow(3) + &a * x_num + &b {
+                    return Err(CurveError::NotOnCurve);
                 }
             }
-            (Some(x_num), None) => {
-                panic!("({}, None) is not valid", x_num);
-            }
-            (None, Some(y_num)) => {
-                panic!("(None, {}) is not valid", y_num);
-            }
+            (Some(_), None) | (None, Some(_)) => return Err(CurveError::InvalidEncoding),
             (None, None) => {}
         }
-        Self { x, y, a, b }
+        Ok(Self { x, y, a, b })
+    }
+
+    /// Adds `rhs`, failing if the points are on different curves or if the
+    /// integer slope does not land the sum on the curve.
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, CurveError> {
+        if self.a != rhs.a || self.b != rhs.b {
+            return Err(CurveError::CurveMismatch);
+        }
+        let infinity = Self {
+            x: None,
+            y: None,
+            a: self.a.clone(),
+            b: self.b.clone(),
+        };
+        match ((&self.x, &self.y), (&rhs.x, &rhs.y)) {
+            ((None, _), _) => Ok(rhs.clone()),
+            (_, (None, _)) => Ok(self.clone()),
+            ((Some(self_x), Some(self_y)), (Some(rhs_x), Some(rhs_y))) => {
+                let slope = if self_x != rhs_x {
+                    (rhs_y - self_y) / (rhs_x - self_x)
+                } else if self_y == rhs_y && *self_y != BigInt::from(0) {
+                    (3 * self_x.pow(2) + &self.a) / (2 * self_y)
+                } else {
+                    return Ok(infinity);
+                };
+                let result_x = &slope * &slope - self_x - rhs_x;
+                let result_y = slope * (self_x - &result_x) - self_y;
+
+                Self::try_new(Some(result_x), Some(result_y), infinity.a, infinity.b)
+            }
+            _ => Err(CurveError::InvalidEncoding),
+        }
     }
 }
 
@@ -54,40 +91,9 @@ impl ops::Add for Point {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        if self.a != rhs.a || self.b != rhs.b {
-            panic!("Points {}, {} are not on the same curve", self, rhs);
-        }
-        match (
-            (self.x.clone(), self.y.clone()),
-            (rhs.x.clone(), rhs.y.clone()),
-        ) {
-            ((None, _), (Some(_), _)) => rhs,
-            ((Some(_), _), (None, _)) => self,
-            ((Some(self_x), Some(self_y)), (Some(rhs_x), Some(rhs_y)))
-                if self_x == rhs_x && self_y == -rhs_y.clone() =>
-            {
-                Self {
-                    x: None,
-                    y: None,
-                    a: self.a,
-                    b: self.b,
-                }
-            }
-            ((Some(self_x), Some(self_y)), (Some(rhs_x), Some(rhs_y))) if self_x != rhs_x => {
-                let slope = (rhs_y - self_y.clone()) / (rhs_x.clone() - self_x.clone());
-                println!("slope: {}", slope.clone());
-                let result_x = slope.clone() * slope.clone() - self_x.clone() - rhs_x;
-                let result_y = slope * (self_x - result_x.clone()) - self_y;
-
-                Self {
-                    x: Some(result_x),
-                    y: Some(result_y),
-                    a: self.a,
-                    b: self.b,
-                }
-            }
-            // Other cases, TODO
-            _ => self,
+        match self.try_add(&rhs) {
+            Ok(sum) => sum,
+            Err(error) => panic!("Cannot add {} and {}: {}", self, rhs, error),
         }
     }
 }
@@ -156,4 +162,68 @@ mod tests {
 
         assert_eq!(p1 + p2, p3);
     }
+
+    #[test]
+    fn fallible_construction_reports_invalid_points() {
+        let a = 5_i32.to_bigint().unwrap();
+        let b = 7_i32.to_bigint().unwrap();
+        let minus_one = Some(-1_i32.to_bigint().unwrap());
+
+        assert_eq!(
+            Point::try_new(
+                minus_one.clone(),
+                Some(-2_i32.to_bigint().unwrap()),
+                a.clone(),
+                b.clone()
+            ),
+            Err(CurveError::NotOnCurve)
+        );
+        assert_eq!(
+            Point::try_new(minus_one.clone(), None, a.clone(), b.clone()),
+            Err(CurveError::InvalidEncoding)
+        );
+        assert_eq!(
+            Point::try_new(None, minus_one, a, b),
+            Err(CurveError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn fallible_addition_reports_points_on_different_curves() {
+        let minus_one = Some(-1_i32.to_bigint().unwrap());
+        let p1 = Point::new(
+            minus_one.clone(),
+            minus_one.clone(),
+            5_i32.to_bigint().unwrap(),
+            7_i32.to_bigint().unwrap(),
+        );
+        let p2 = Point::new(
+            minus_one.clone(),
+            minus_one,
+            0_i32.to_bigint().unwrap(),
+            2_i32.to_bigint().unwrap(),
+        );
+
+        assert_eq!(p1.try_add(&p2), Err(CurveError::CurveMismatch));
+    }
+
+    #[test]
+    fn double_a_point() {
+        let a = 5_i32.to_bigint().unwrap();
+        let b = 7_i32.to_bigint().unwrap();
+        let p1 = Point::new(
+            Some(-1_i32.to_bigint().unwrap()),
+            Some(-1_i32.to_bigint().unwrap()),
+            a.clone(),
+            b.clone(),
+        );
+        let p2 = Point::new(
+            Some(18_i32.to_bigint().unwrap()),
+            Some(77_i32.to_bigint().unwrap()),
+            a,
+            b,
+        );
+
+        assert_eq!(p1.try_add(&p1), Ok(p2));
+    }
 }
@@ -1 +1,2 @@
-mod point;
+pub use elliptic_curve::errors;
+pub mod point;
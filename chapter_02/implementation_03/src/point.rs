@@ -1,4 +1,4 @@
-use anyhow::ensure;
+use crate::errors::CurveError;
 use std::ops::Add;
 
 #[derive(Clone, Copy, Debug)]
@@ -8,9 +8,52 @@ pub enum Point<const A: i64, const B: i64> {
 }
 
 impl<const A: i64, const B: i64> Point<A, B> {
-    pub fn new_point(x: i64, y: i64) -> Result<Self, anyhow::Error> {
-        ensure!(y.pow(2) == (x.pow(3) + A * x + B), "Point is not on the curve");
-        Ok(Point::<A, B>::Point(x, y))
+    pub fn new_point(x: i64, y: i64) -> Result<Self, CurveError> {
+        Self::try_new(x, y)
+    }
+
+    /// Builds the point (x, y), failing if it is off the curve or too large
+    /// to evaluate the curve equation in an `i64`.
+    pub fn try_new(x: i64, y: i64) -> Result<Self, CurveError> {
+        let left_side = y.checked_pow(2);
+        let right_side = x
+            .checked_pow(3)
+            .zip(A.checked_mul(x))
+            .and_then(|(cube, linear)| cube.checked_add(linear)?.checked_add(B));
+        match left_side.zip(right_side) {
+            Some((left_side, right_side)) if left_side == right_side => {
+                Ok(Point::<A, B>::Point(x, y))
+            }
+            Some(_) => Err(CurveError::NotOnCurve),
+            None => Err(CurveError::Overflow),
+        }
+    }
+
+    /// Adds `other`, failing if the integer slope does not land the sum on
+    /// the curve or the coordinates overflow.
+    pub fn try_add(&self, other: &Self) -> Result<Self, CurveError> {
+        let (x1, y1, x2, y2) = match (*self, *other) {
+            (Self::Infinity, _) => return Ok(*other),
+            (_, Self::Infinity) => return Ok(*self),
+            (Self::Point(x1, y1), Self::Point(x2, y2)) => (x1, y1, x2, y2),
+        };
+        if x1 == x2 && (y1 != y2 || y1 == 0) {
+            return Ok(Self::new_infinity());
+        }
+        let slope = if x1 == x2 {
+            tangent_slope::<A>(x1, y1)
+        } else {
+            y2.checked_sub(y1).zip(x2.checked_sub(x1)).and_then(|(dy, dx)| dy.checked_div(dx))
+        };
+        let sum = slope.and_then(|slope| {
+            let x3 = slope.checked_pow(2)?.checked_sub(x1.checked_add(x2)?)?;
+            let y3 = slope.checked_mul(x1.checked_sub(x3)?)?.checked_sub(y1)?;
+            Some((x3, y3))
+        });
+        match sum {
+            Some((x3, y3)) => Self::try_new(x3, y3),
+            None => Err(CurveError::Overflow),
+        }
     }
 
     pub fn new_infinity() -> Self {
@@ -18,6 +61,11 @@ impl<const A: i64, const B: i64> Point<A, B> {
     }
 }
 
+fn tangent_slope<const A: i64>(x: i64, y: i64) -> Option<i64> {
+    let numerator = x.checked_pow(2)?.checked_mul(3)?.checked_add(A)?;
+    numerator.checked_div(y.checked_mul(2)?)
+}
+
 impl<const A: i64, const B: i64> PartialEq for Point<A, B> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -31,25 +79,9 @@ impl<const A: i64, const B: i64> PartialEq for Point<A, B> {
 impl<const A: i64, const B: i64> Add<Point<A, B>> for Point<A, B> {
     type Output = Self;
     fn add(self, other: Point<A, B>) -> Self {
-        match (self, other) {
-            (Self::Infinity, Self::Infinity) => Self::new_infinity(),
-            (Self::Infinity, Self::Point(_, _)) => other,
-            (Self::Point(_, _), Self::Infinity) => self,
-            (Self::Point(x1, y1), Self::Point(x2, y2)) if x1 == x2 && y1 != y2 => Self::new_infinity(),
-            (Self::Point(x1, y1), Self::Point(x2, y2)) if x1 == x2 && (y1 == 0 || y2 == 0) => Self::new_infinity(),
-            (Self::Point(x1, y1), Self::Point(x2, y2)) if x1 == x2 && y1 == y2 => {
-                let slope = (3 * x1.pow(2) + A) / (2 * y1);
-                let x3 = slope.pow(2) - (2 * x1);
-                let y3 = slope * (x1 - x3) - y1;
-                Self::new_point(x3, y3).unwrap()
-            },
-            (Self::Point(x1, y1), Self::Point(x2, y2)) if x1 != x2 => {
-                let slope = (y2 - y1) / (x2 - x1);
-                let x3 = slope.pow(2) - (x1 + x2);
-                let y3 = slope * (x1 - x3) - y1;
-                Self::new_point(x3, y3).unwrap()
-            },
-            _ => todo!(),
+        match self.try_add(&other) {
+            Ok(sum) => sum,
+            Err(error) => panic!("Cannot add {:?} and {:?}: {}", self, other, error),
         }
     }
 }
@@ -112,4 +144,27 @@ mod point_tests {
 
         assert_eq!(point1 + point2, expected_point3);
     }
+
+    #[test]
+    fn test07_fallible_creation_reports_the_error() {
+        assert_eq!(Point::<5, 7>::try_new(-1, -2), Err(CurveError::NotOnCurve));
+        assert_eq!(Point::<5, 7>::try_new(i64::MAX, 1), Err(CurveError::Overflow));
+    }
+
+    #[test]
+    fn test08_a_point_can_be_doubled() {
+        let point = Point::<5, 7>::new_point(-1, -1).unwrap();
+        let expected_point = Point::<5, 7>::new_point(18, 77).unwrap();
+
+        assert_eq!(point.try_add(&point), Ok(expected_point));
+        assert_eq!(point + point, expected_point);
+    }
+
+    #[test]
+    fn test09_sums_off_the_integer_curve_are_errors() {
+        let point1 = Point::<5, 7>::new_point(2, 5).unwrap();
+        let point2 = Point::<5, 7>::new_point(18, 77).unwrap();
+
+        assert_eq!(point1.try_add(&point2), Err(CurveError::NotOnCurve));
+    }
 }
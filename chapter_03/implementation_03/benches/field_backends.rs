@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use programming_bitcoin_rs::field::{batch_invert, Field};
+use programming_bitcoin_rs::montgomery_field::S256MontgomeryField;
+use programming_bitcoin_rs::secp256k1::{S256Field, P};
+
+fn bench_backend<F: Field>(c: &mut Criterion, name: &str, a: F, b: F) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("mul", |bencher| {
+        bencher.iter(|| black_box(a.clone()) * black_box(b.clone()))
+    });
+    group.bench_function("inverse", |bencher| {
+        bencher.iter(|| black_box(&a).inverse())
+    });
+    group.bench_function("batch_invert_64", |bencher| {
+        let elements = vec![a.clone(); 64];
+        bencher.iter(|| batch_invert(&mut black_box(elements.clone())))
+    });
+    group.bench_function("sqrt", |bencher| bencher.iter(|| black_box(&b).sqrt()));
+    group.finish();
+}
+
+fn field_backends(c: &mut Criterion) {
+    bench_backend(
+        c,
+        "s256_field",
+        S256Field::from(17),
+        S256Field::new(&*P - 56),
+    );
+    bench_backend(
+        c,
+        "montgomery_field",
+        S256MontgomeryField::from(17_i64),
+        S256MontgomeryField::from(-56_i64),
+    );
+}
+
+criterion_group!(benches, field_backends);
+criterion_main!(benches);
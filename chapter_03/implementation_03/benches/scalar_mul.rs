@@ -0,0 +1,76 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use num_bigint::BigInt;
+use programming_bitcoin_rs::jacobian_point::JacobianPoint;
+use programming_bitcoin_rs::secp256k1::{S256Point, G, N};
+
+fn scalar_mul(c: &mut Criterion) {
+    let k = &*N - BigInt::from(0x1234_5678_9abc_def0_u64);
+    let point = G.scalar_mul(&BigInt::from(7));
+    // Build the generator tables outside of the measurements
+    S256Point::mul_generator_ct(&k);
+
+    let mut group = c.benchmark_group("scalar_mul");
+    group.sample_size(20);
+    group.bench_function("double_and_add", |bencher| {
+        bencher.iter(|| black_box(&point).scalar_mul(black_box(&k)))
+    });
+    group.bench_function("jacobian_double_and_add", |bencher| {
+        bencher.iter(|| JacobianPoint::scalar_mul(black_box(&point), black_box(&k)))
+    });
+    group.bench_function("wnaf", |bencher| {
+        bencher.iter(|| black_box(&point).mul_wnaf(black_box(&k)))
+    });
+    group.bench_function("constant_time_ladder", |bencher| {
+        bencher.iter(|| black_box(&point).scalar_mul_ct(black_box(&k)))
+    });
+    group.bench_function("generator_double_and_add", |bencher| {
+        bencher.iter(|| G.scalar_mul(black_box(&k)))
+    });
+    group.bench_function("generator_table", |bencher| {
+        bencher.iter(|| S256Point::mul_generator(black_box(&k)))
+    });
+    group.bench_function("generator_table_constant_time", |bencher| {
+        bencher.iter(|| S256Point::mul_generator_ct(black_box(&k)))
+    });
+    group.finish();
+}
+
+fn multi_mul(c: &mut Criterion) {
+    let u = &*N - BigInt::from(0x1234_5678_9abc_def0_u64);
+    let v = &*N - BigInt::from(0x0fed_cba9_8765_4321_u64);
+    let point = G.scalar_mul(&BigInt::from(7));
+    let terms: Vec<_> = (1..=128_u64)
+        .map(|i| (&u - BigInt::from(i), G.scalar_mul(&BigInt::from(i))))
+        .collect();
+    S256Point::mul_generator(&u);
+
+    let mut group = c.benchmark_group("multi_mul");
+    group.sample_size(10);
+    group.bench_function("verification_separate", |bencher| {
+        bencher.iter(|| S256Point::mul_generator(black_box(&u)) + point.mul_wnaf(black_box(&v)))
+    });
+    group.bench_function("verification_strauss", |bencher| {
+        bencher.iter(|| {
+            S256Point::multi_mul(black_box(&[
+                (u.clone(), G.clone()),
+                (v.clone(), point.clone()),
+            ]))
+        })
+    });
+    group.bench_function("128_terms_separate", |bencher| {
+        bencher.iter(|| {
+            terms
+                .iter()
+                .fold(S256Point::new_infinity(), |sum, (k, point)| {
+                    sum + point.mul_wnaf(black_box(k))
+                })
+        })
+    });
+    group.bench_function("128_terms_pippenger", |bencher| {
+        bencher.iter(|| S256Point::multi_mul(black_box(&terms)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, scalar_mul, multi_mul);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use crate::base58::{decode_check, encode_check};
+use crate::errors::AddressError;
+use crate::network::Network;
+
+/// P2PKH address paying to `hash` on `network`.
+pub fn p2pkh_address(hash: &[u8; 20], network: Network) -> String {
+    let mut payload = vec![network.p2pkh_version()];
+    payload.extend_from_slice(hash);
+    encode_check(&payload)
+}
+
+/// Parses a P2PKH address into its network and the hash160 it pays to.
+pub fn parse_p2pkh_address(address: &str) -> Result<(Network, [u8; 20]), AddressError> {
+    let payload = decode_check(address)?;
+    if payload.len() != 21 {
+        return Err(AddressError::InvalidLength(payload.len()));
+    }
+    let network =
+        Network::from_p2pkh_version(payload[0]).ok_or(AddressError::UnknownVersion(payload[0]))?;
+    let mut hash = [0_u8; 20];
+    hash.copy_from_slice(&payload[1..]);
+    Ok((network, hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mainnet_and_testnet_addresses() {
+        let (network, hash) = parse_p2pkh_address("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap();
+        assert_eq!(network, Network::Mainnet);
+        assert_eq!(
+            hex::encode(hash),
+            "62e907b15cbf27d5425399ebf6f0fb50ebb88f18"
+        );
+
+        let (network, hash) = parse_p2pkh_address("mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA").unwrap();
+        assert_eq!(network, Network::Testnet);
+        assert_eq!(
+            p2pkh_address(&hash, Network::Regtest),
+            "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_addresses() {
+        let p2sh = encode_check(&[vec![0x05], vec![0_u8; 20]].concat());
+        let too_short = encode_check(&[0x00; 20]);
+
+        assert_eq!(
+            parse_p2pkh_address(&p2sh),
+            Err(AddressError::UnknownVersion(0x05))
+        );
+        assert_eq!(
+            parse_p2pkh_address(&too_short),
+            Err(AddressError::InvalidLength(20))
+        );
+        assert!(matches!(
+            parse_p2pkh_address("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Err(AddressError::Base58(_))
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::errors::Base58Error;
+use crate::helpers::hash256;
+use num_bigint::{BigInt, Sign};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58 encoding of `data`, where every leading zero byte becomes a '1'.
+pub fn encode(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&byte| byte == 0).count();
+    let mut num = BigInt::from_bytes_be(Sign::Plus, data);
+    let mut digits = Vec::new();
+    while num > BigInt::from(0) {
+        let remainder = usize::try_from(&num % 58).unwrap();
+        digits.push(ALPHABET[remainder]);
+        num /= 58;
+    }
+    digits.extend(std::iter::repeat_n(b'1', zeros));
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+/// Decodes a base58 string, turning every leading '1' back into a zero byte.
+pub fn decode(encoded: &str) -> Result<Vec<u8>, Base58Error> {
+    let mut num = BigInt::from(0);
+    for character in encoded.chars() {
+        let digit = ALPHABET
+            .iter()
+            .position(|&symbol| symbol as char == character)
+            .ok_or(Base58Error::InvalidCharacter(character))?;
+        num = num * 58 + digit;
+    }
+    let zeros = encoded
+        .chars()
+        .take_while(|&character| character == '1')
+        .count();
+    let mut decoded = vec![0_u8; zeros];
+    if num > BigInt::from(0) {
+        decoded.extend(num.to_bytes_be().1);
+    }
+    Ok(decoded)
+}
+
+/// Base58 encoding of `data` followed by the first four bytes of its hash256.
+pub fn encode_check(data: &[u8]) -> String {
+    let mut payload = data.to_vec();
+    payload.extend_from_slice(&hash256(data)[..4]);
+    encode(&payload)
+}
+
+/// Decodes a base58 string and validates and strips its four byte checksum.
+pub fn decode_check(encoded: &str) -> Result<Vec<u8>, Base58Error> {
+    let mut payload = decode(encoded)?;
+    if payload.len() < 4 {
+        return Err(Base58Error::TooShort(payload.len()));
+    }
+    let checksum = payload.split_off(payload.len() - 4);
+    if hash256(&payload)[..4] != checksum[..] {
+        return Err(Base58Error::InvalidChecksum);
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn encode_vectors_from_the_book() {
+        let vectors = [
+            (
+                "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+                "9MA8fRQrT4u8Zj8ZRd6MAiiyaxb2Y1CMpvVkHQu5hVM6",
+            ),
+            (
+                "eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+                "4fE3H2E6XMp4SsxtwinF7w9a34ooUrwWe4WsW1458Pd",
+            ),
+            (
+                "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+                "EQJsjkd6JaGwxrjEhfeqPenqHwrBmPQZjJGNSCHBkcF7",
+            ),
+        ];
+        for (data, encoded) in vectors {
+            let data = hex::decode(data).unwrap();
+
+            assert_eq!(encode(&data), encoded);
+            assert_eq!(decode(encoded).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn leading_zero_bytes_are_kept() {
+        assert_eq!(encode(&[]), "");
+        assert_eq!(encode(&[0, 0, 0]), "111");
+        assert_eq!(encode(&hex::decode("0000287fb4cd").unwrap()), "11233QC4");
+        assert_eq!(decode("111").unwrap(), vec![0, 0, 0]);
+        assert_eq!(
+            decode("11233QC4").unwrap(),
+            hex::decode("0000287fb4cd").unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_check_the_genesis_address() {
+        let payload = hex::decode("0062e907b15cbf27d5425399ebf6f0fb50ebb88f18").unwrap();
+
+        assert_eq!(encode_check(&payload), "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+        assert_eq!(
+            decode_check("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap(),
+            payload
+        );
+    }
+
+    #[test]
+    fn invalid_strings_are_rejected() {
+        assert_eq!(decode("0OIl"), Err(Base58Error::InvalidCharacter('0')));
+        assert_eq!(decode_check("111"), Err(Base58Error::TooShort(3)));
+        assert_eq!(
+            decode_check("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            Err(Base58Error::InvalidChecksum)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn encode_decode_round_trip(data in proptest::collection::vec(any::<u8>(), 0..64)) {
+            prop_assert_eq!(decode(&encode(&data)).unwrap(), data);
+        }
+
+        #[test]
+        fn encode_check_decode_check_round_trip(
+            zeros in 0_usize..4,
+            data in proptest::collection::vec(any::<u8>(), 0..64),
+        ) {
+            let data = [vec![0_u8; zeros], data].concat();
+            prop_assert_eq!(decode_check(&encode_check(&data)).unwrap(), data);
+        }
+    }
+}
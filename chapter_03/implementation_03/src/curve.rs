@@ -0,0 +1,91 @@
+use crate::field::Field;
+use crate::finite_field_element::FiniteFieldElement;
+use crate::point::Point;
+use num_bigint::BigInt;
+use std::fmt::Debug;
+
+/// Parameters of a short Weierstrass curve y^2 = x^3 + a*x + b together with
+/// a generator of a subgroup of prime order.
+pub trait Curve: Sized + Clone + Debug + PartialEq {
+    type Field: Field + From<i64>;
+
+    fn a() -> Self::Field;
+
+    fn b() -> Self::Field;
+
+    fn generator() -> Point<Self>;
+
+    /// Order of the subgroup generated by `generator`.
+    fn order() -> BigInt;
+
+    /// Number of points on the curve divided by `order`.
+    fn cofactor() -> BigInt;
+}
+
+/// The curve y^2 = x^3 + 7 over the field of order 223 used in the book's
+/// examples, generated by (47, 71).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct F223Curve;
+
+impl Curve for F223Curve {
+    type Field = FiniteFieldElement<223>;
+
+    fn a() -> Self::Field {
+        Self::Field::from(0)
+    }
+
+    fn b() -> Self::Field {
+        Self::Field::from(7)
+    }
+
+    fn generator() -> Point<Self> {
+        Point::new_point(Self::Field::from(47), Self::Field::from(71)).unwrap()
+    }
+
+    fn order() -> BigInt {
+        BigInt::from(21)
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secp256k1::Secp256k1;
+
+    fn check_generator_order<C: Curve>() {
+        let generator = C::generator();
+
+        assert_ne!(generator, Point::new_infinity());
+        assert_eq!(generator.scalar_mul(&C::order()), Point::new_infinity());
+        assert_eq!(generator.scalar_mul(&(C::order() + 1)), C::generator());
+    }
+
+    #[test]
+    fn generators_have_the_declared_order() {
+        check_generator_order::<F223Curve>();
+        check_generator_order::<Secp256k1>();
+    }
+
+    #[test]
+    fn f223_curve_has_order_times_cofactor_points() {
+        let mut points = 1;
+        for x in 0..223 {
+            for y in 0..223 {
+                let x = FiniteFieldElement::<223>::from(x);
+                let y = FiniteFieldElement::<223>::from(y);
+                if Point::<F223Curve>::new_point(x, y).is_ok() {
+                    points += 1;
+                }
+            }
+        }
+
+        assert_eq!(
+            BigInt::from(points),
+            F223Curve::order() * F223Curve::cofactor()
+        );
+    }
+}
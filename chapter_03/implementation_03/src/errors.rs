@@ -0,0 +1,331 @@
+pub use elliptic_curve::errors::CurveError;
+pub use programming_bitcoin::errors::NotPrimeError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedEndError;
+
+impl fmt::Display for UnexpectedEndError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Data ended unexpectedly")
+    }
+}
+
+impl std::error::Error for UnexpectedEndError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VarintError {
+    UnexpectedEnd,
+    /// The value was encoded with more bytes than its shortest form.
+    NonCanonical(u64),
+}
+
+impl fmt::Display for VarintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VarintError::UnexpectedEnd => write!(f, "Varint ended unexpectedly"),
+            VarintError::NonCanonical(value) => {
+                write!(f, "Non-canonical varint encoding of {}", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VarintError {}
+
+impl From<UnexpectedEndError> for VarintError {
+    fn from(_: UnexpectedEndError) -> Self {
+        VarintError::UnexpectedEnd
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivisionByZeroError;
+
+impl fmt::Display for DivisionByZeroError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cannot divide by zero-valued field element")
+    }
+}
+
+impl std::error::Error for DivisionByZeroError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerOutOfRangeError;
+
+impl fmt::Display for IntegerOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Integer does not fit in the requested unsigned bytes")
+    }
+}
+
+impl std::error::Error for IntegerOutOfRangeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureOutOfRangeError;
+
+impl fmt::Display for SignatureOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Signature values must be in the range 1 to N - 1")
+    }
+}
+
+impl std::error::Error for SignatureOutOfRangeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecError {
+    InvalidLength(usize),
+    InvalidPrefix(u8),
+    CoordinateOutOfRange,
+    NotOnCurve,
+    PointAtInfinity,
+}
+
+impl fmt::Display for SecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SecError::InvalidLength(length) => {
+                write!(f, "Invalid SEC encoding length: {} bytes", length)
+            }
+            SecError::InvalidPrefix(prefix) => write!(f, "Invalid SEC prefix: {:#04x}", prefix),
+            SecError::CoordinateOutOfRange => write!(f, "Coordinate is not a field element"),
+            SecError::NotOnCurve => write!(f, "Point is not on the curve"),
+            SecError::PointAtInfinity => write!(f, "The point at infinity is not a public key"),
+        }
+    }
+}
+
+impl std::error::Error for SecError {}
+
+impl From<SecError> for CurveError {
+    fn from(error: SecError) -> Self {
+        match error {
+            SecError::NotOnCurve => CurveError::NotOnCurve,
+            _ => CurveError::InvalidEncoding,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DerError {
+    InvalidLength(usize),
+    InvalidSequenceMarker(u8),
+    LengthMismatch,
+    InvalidIntegerMarker(u8),
+    ZeroLengthInteger,
+    NegativeInteger,
+    ExcessivePadding,
+    OutOfRange,
+}
+
+impl fmt::Display for DerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DerError::InvalidLength(length) => {
+                write!(f, "Invalid DER signature length: {} bytes", length)
+            }
+            DerError::InvalidSequenceMarker(marker) => {
+                write!(f, "Expected sequence marker 0x30, found {:#04x}", marker)
+            }
+            DerError::LengthMismatch => {
+                write!(f, "Declared lengths do not match the signature length")
+            }
+            DerError::InvalidIntegerMarker(marker) => {
+                write!(f, "Expected integer marker 0x02, found {:#04x}", marker)
+            }
+            DerError::ZeroLengthInteger => write!(f, "Integers can not be empty"),
+            DerError::NegativeInteger => write!(f, "Integers can not be negative"),
+            DerError::ExcessivePadding => write!(f, "Integers must be minimally encoded"),
+            DerError::OutOfRange => write!(f, "{}", SignatureOutOfRangeError),
+        }
+    }
+}
+
+impl std::error::Error for DerError {}
+
+impl From<SignatureOutOfRangeError> for DerError {
+    fn from(_: SignatureOutOfRangeError) -> Self {
+        DerError::OutOfRange
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base58Error {
+    InvalidCharacter(char),
+    TooShort(usize),
+    InvalidChecksum,
+}
+
+impl fmt::Display for Base58Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base58Error::InvalidCharacter(character) => {
+                write!(f, "Invalid base58 character: {:?}", character)
+            }
+            Base58Error::TooShort(length) => {
+                write!(f, "Payload of {} bytes is too short for a checksum", length)
+            }
+            Base58Error::InvalidChecksum => write!(f, "Invalid base58 checksum"),
+        }
+    }
+}
+
+impl std::error::Error for Base58Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    Base58(Base58Error),
+    InvalidLength(usize),
+    UnknownVersion(u8),
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::Base58(error) => write!(f, "{}", error),
+            AddressError::InvalidLength(length) => {
+                write!(f, "Invalid address payload length: {} bytes", length)
+            }
+            AddressError::UnknownVersion(version) => {
+                write!(f, "Unknown address version byte: {:#04x}", version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+impl From<Base58Error> for AddressError {
+    fn from(error: Base58Error) -> Self {
+        AddressError::Base58(error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyError {
+    SecretOutOfRange,
+    MessageHashOutOfRange,
+    PublicKeyAtInfinity,
+    NonceOutOfRange,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyError::SecretOutOfRange => write!(f, "Secret must be in the range 1 to N - 1"),
+            KeyError::MessageHashOutOfRange => {
+                write!(f, "Message hash must be in the range 0 to 2^256 - 1")
+            }
+            KeyError::PublicKeyAtInfinity => {
+                write!(f, "The point at infinity is not a public key")
+            }
+            KeyError::NonceOutOfRange => write!(f, "Nonce must be in the range 1 to N - 1"),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WifError {
+    Base58(Base58Error),
+    InvalidLength(usize),
+    UnknownPrefix(u8),
+    InvalidCompressionFlag(u8),
+    Key(KeyError),
+}
+
+impl fmt::Display for WifError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WifError::Base58(error) => write!(f, "{}", error),
+            WifError::InvalidLength(length) => {
+                write!(f, "Invalid WIF payload length: {} bytes", length)
+            }
+            WifError::UnknownPrefix(prefix) => write!(f, "Unknown WIF prefix: {:#04x}", prefix),
+            WifError::InvalidCompressionFlag(flag) => {
+                write!(f, "Invalid WIF compression flag: {:#04x}", flag)
+            }
+            WifError::Key(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for WifError {}
+
+impl From<Base58Error> for WifError {
+    fn from(error: Base58Error) -> Self {
+        WifError::Base58(error)
+    }
+}
+
+impl From<KeyError> for WifError {
+    fn from(error: KeyError) -> Self {
+        WifError::Key(error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxError {
+    UnexpectedEnd,
+    TrailingBytes(usize),
+    SegwitNotSupported,
+    NonCanonicalVarint(u64),
+}
+
+impl fmt::Display for TxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TxError::UnexpectedEnd => write!(f, "Transaction ended unexpectedly"),
+            TxError::TrailingBytes(count) => {
+                write!(f, "{} bytes left after the end of the transaction", count)
+            }
+            TxError::SegwitNotSupported => {
+                write!(f, "Segwit transactions are not supported")
+            }
+            TxError::NonCanonicalVarint(value) => {
+                write!(f, "Non-canonical varint encoding of {}", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TxError {}
+
+impl From<UnexpectedEndError> for TxError {
+    fn from(_: UnexpectedEndError) -> Self {
+        TxError::UnexpectedEnd
+    }
+}
+
+impl From<VarintError> for TxError {
+    fn from(error: VarintError) -> Self {
+        match error {
+            VarintError::UnexpectedEnd => TxError::UnexpectedEnd,
+            VarintError::NonCanonical(value) => TxError::NonCanonicalVarint(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    UnexpectedEnd,
+    InvalidAsmToken(String),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScriptError::UnexpectedEnd => write!(f, "Push runs past the end of the script"),
+            ScriptError::InvalidAsmToken(token) => write!(f, "Invalid ASM token: {:?}", token),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl From<UnexpectedEndError> for ScriptError {
+    fn from(_: UnexpectedEndError) -> Self {
+        ScriptError::UnexpectedEnd
+    }
+}
@@ -0,0 +1,368 @@
+use crate::errors::DivisionByZeroError;
+use crate::helpers::int_to_big_endian;
+use num_bigint::{BigInt, BigUint};
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Arithmetic shared by every prime field implementation, so curve and
+/// protocol code can be written once for all of them.
+///
+/// Some implementations only know their modulus at runtime, so constructors
+/// build elements of the same field as `self` instead of being static.
+pub trait Field:
+    Sized
+    + Clone
+    + PartialEq
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Additive identity of the field `self` belongs to.
+    fn zero(&self) -> Self;
+
+    /// Multiplicative identity of the field `self` belongs to.
+    fn one(&self) -> Self;
+
+    /// Order of the field.
+    fn modulus(&self) -> BigUint;
+
+    /// Element of the same field as `self` from its big-endian encoding, or
+    /// `None` when the value is not below the modulus.
+    fn element_from_bytes(&self, bytes: &[u8]) -> Option<Self>;
+
+    /// Big-endian encoding using as many bytes as the modulus needs.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    fn is_zero(&self) -> bool {
+        *self == self.zero()
+    }
+
+    /// Raises the element to `exponent` by square-and-multiply.
+    fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = self.one();
+        for i in (0..exponent.bits()).rev() {
+            result = result.clone() * result;
+            if exponent.bit(i) {
+                result = result * self.clone();
+            }
+        }
+        result
+    }
+
+    /// Multiplicative inverse, or `None` for zero.
+    ///
+    /// Defaults to Fermat's little theorem, which takes the same steps for
+    /// every non-zero element.
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(&(self.modulus() - 2_u32)))
+    }
+
+    /// `self / other`, or an error when `other` is zero.
+    fn checked_div(&self, other: &Self) -> Result<Self, DivisionByZeroError> {
+        let other_inverse = other.inverse().ok_or(DivisionByZeroError)?;
+        Ok(self.clone() * other_inverse)
+    }
+
+    /// Legendre symbol of the element: 1 for non-zero squares, -1 for
+    /// non-squares and 0 for zero, by Euler's criterion.
+    fn legendre(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        if self.pow(&((self.modulus() - 1_u32) >> 1)) == self.one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// A square root of the element, or `None` when it is not a square.
+    ///
+    /// Uses `self^((p + 1) / 4)` when p % 4 == 3 and Tonelli-Shanks otherwise.
+    fn sqrt(&self) -> Option<Self> {
+        let modulus = self.modulus();
+        if self.is_zero() || modulus == BigUint::from(2_u32) {
+            return Some(self.clone());
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+        if modulus.bit(1) {
+            return Some(self.pow(&((modulus + 1_u32) >> 2)));
+        }
+
+        // p - 1 == q * 2^s with q odd
+        let s = (&modulus - 1_u32).trailing_zeros().unwrap_or(0);
+        let q = (&modulus - 1_u32) >> s;
+        let mut non_residue = self.one() + self.one();
+        while non_residue.legendre() != -1 {
+            non_residue = non_residue + self.one();
+        }
+
+        let mut m = s;
+        let mut c = non_residue.pow(&q);
+        let mut t = self.pow(&q);
+        let mut root = self.pow(&((q + 1_u32) >> 1));
+        while t != self.one() {
+            // Least i such that t^(2^i) == 1, which is always below m
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while t_power != self.one() {
+                t_power = t_power.clone() * t_power;
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.clone() * b;
+            }
+            m = i;
+            c = b.clone() * b.clone();
+            t = t * c.clone();
+            root = root * b;
+        }
+        Some(root)
+    }
+}
+
+/// Inverts every element of `elements` in place with a single field inversion
+/// using Montgomery's trick.
+///
+/// Fails without modifying `elements` when one of them is zero.
+pub fn batch_invert<F: Field>(elements: &mut [F]) -> Result<(), DivisionByZeroError> {
+    let Some(first) = elements.first() else {
+        return Ok(());
+    };
+    // prefix_products[i] holds elements[0] * ... * elements[i - 1]
+    let mut prefix_products = Vec::with_capacity(elements.len());
+    let mut accumulator = first.one();
+    for element in elements.iter() {
+        prefix_products.push(accumulator.clone());
+        accumulator = accumulator * element.clone();
+    }
+
+    let mut inverse = accumulator.inverse().ok_or(DivisionByZeroError)?;
+    for (element, prefix_product) in elements.iter_mut().zip(prefix_products).rev() {
+        let element_inverse = inverse.clone() * prefix_product;
+        inverse = inverse * element.clone();
+        *element = element_inverse;
+    }
+    Ok(())
+}
+
+/// Inverse of `value` modulo `modulus` by the extended Euclidean algorithm,
+/// or `None` when they are not coprime.
+pub(crate) fn mod_inverse(value: &BigInt, modulus: &BigInt) -> Option<BigInt> {
+    let (mut old_r, mut r) = (value.clone(), modulus.clone());
+    let (mut old_t, mut t) = (BigInt::from(1), BigInt::from(0));
+    while r != BigInt::from(0) {
+        let quotient = &old_r / &r;
+        (old_r, r) = (r.clone(), old_r - &quotient * r);
+        (old_t, t) = (t.clone(), old_t - quotient * t);
+    }
+    if old_r != BigInt::from(1) {
+        return None;
+    }
+    Some(((old_t % modulus) + modulus) % modulus)
+}
+
+/// Number of bytes needed to encode every element below `modulus`.
+pub(crate) fn byte_length(modulus: &BigUint) -> usize {
+    modulus.bits().div_ceil(8) as usize
+}
+
+/// Big-endian encoding of the reduced `value` left padded to `length`
+/// bytes, the byte length of its modulus.
+pub(crate) fn to_padded_bytes(value: &BigUint, length: usize) -> Vec<u8> {
+    int_to_big_endian(&BigInt::from(value.clone()), length)
+        .expect("Reduced values fit in the byte length of the modulus")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field_element::FieldElement;
+    use crate::finite_field_element::FiniteFieldElement;
+    use crate::montgomery_field::{MontgomeryFieldElement, Secp256k1Modulus};
+    use crate::secp256k1::{S256Field, P};
+    use crate::u32_field_element::U32FieldElement;
+    use num_bigint::BigInt;
+
+    /// Checks the field axioms the trait relies on for a few elements.
+    fn check_field<F: Field>(a: F, b: F) {
+        let zero = a.zero();
+        let one = a.one();
+
+        assert!(zero.is_zero());
+        assert!(!one.is_zero());
+        assert_eq!(a.clone() + zero.clone(), a);
+        assert_eq!(a.clone() * one.clone(), a);
+        assert_eq!(a.clone() - a.clone(), zero);
+        assert_eq!(a.clone() * a.inverse().unwrap(), one);
+        assert_eq!((a.clone() / b.clone()) * b.clone(), a);
+        assert_eq!(a.checked_div(&b), Ok(a.clone() / b.clone()));
+        assert_eq!(a.checked_div(&zero), Err(DivisionByZeroError));
+        assert_eq!(zero.inverse(), None);
+        assert_eq!(
+            a.pow(&BigUint::from(3_u32)),
+            a.clone() * a.clone() * a.clone()
+        );
+        assert_eq!(a.pow(&(a.modulus() - 1_u32)), one);
+
+        let square = a.clone() * a.clone();
+        let root = square.sqrt().unwrap();
+        assert_eq!(root.clone() * root, square);
+
+        let bytes = a.to_bytes();
+        assert_eq!(bytes.len(), byte_length(&a.modulus()));
+        assert_eq!(a.element_from_bytes(&bytes), Some(a.clone()));
+        assert_eq!(a.element_from_bytes(&a.modulus().to_bytes_be()), None);
+    }
+
+    #[test]
+    fn finite_field_element_is_a_field() {
+        check_field(
+            FiniteFieldElement::<223>::new(17),
+            FiniteFieldElement::<223>::new(56),
+        );
+    }
+
+    #[test]
+    fn bigint_field_element_is_a_field() {
+        let prime = BigInt::from(223);
+        check_field(
+            FieldElement::new(BigInt::from(17), prime.clone()),
+            FieldElement::new(BigInt::from(56), prime),
+        );
+    }
+
+    #[test]
+    fn u32_field_element_is_a_field() {
+        check_field(
+            U32FieldElement::new(17, 4_294_967_291),
+            U32FieldElement::new(4_000_000_000, 4_294_967_291),
+        );
+    }
+
+    #[test]
+    fn s256_field_is_a_field() {
+        check_field(S256Field::from(17), S256Field::new(&*P - 56));
+    }
+
+    #[test]
+    fn montgomery_field_element_is_a_field() {
+        check_field(
+            MontgomeryFieldElement::<Secp256k1Modulus>::from(17_i64),
+            MontgomeryFieldElement::<Secp256k1Modulus>::from(-56_i64),
+        );
+    }
+
+    #[test]
+    fn non_squares_have_no_square_root() {
+        // 3 is not a quadratic residue modulo 223
+        assert_eq!(FiniteFieldElement::<223>::new(3).sqrt(), None);
+    }
+
+    #[test]
+    fn batch_invert_matches_single_inversions() {
+        let mut elements: Vec<_> = (1..=20)
+            .map(|i| S256Field::new(&*P - BigInt::from(i * 7919)))
+            .collect();
+        let expected: Vec<_> = elements.iter().map(|e| e.inverse().unwrap()).collect();
+
+        batch_invert(&mut elements).unwrap();
+
+        assert_eq!(elements, expected);
+    }
+
+    #[test]
+    fn batch_invert_rejects_zero_without_modifying_the_input() {
+        let mut elements: Vec<_> = [3, 0, 5]
+            .into_iter()
+            .map(FiniteFieldElement::<223>::from)
+            .collect();
+        let original = elements.clone();
+
+        assert_eq!(batch_invert(&mut elements), Err(DivisionByZeroError));
+        assert_eq!(elements, original);
+        assert_eq!(batch_invert::<FiniteFieldElement<223>>(&mut []), Ok(()));
+    }
+
+    #[test]
+    fn mod_inverse_by_extended_euclid() {
+        assert_eq!(
+            mod_inverse(&BigInt::from(3), &BigInt::from(11)),
+            Some(BigInt::from(4))
+        );
+        assert_eq!(mod_inverse(&BigInt::from(6), &BigInt::from(9)), None);
+        assert_eq!(mod_inverse(&BigInt::from(0), &BigInt::from(11)), None);
+
+        let value = &*P - 56;
+        let inverse = mod_inverse(&value, &P).unwrap();
+        assert_eq!((value * inverse) % &*P, BigInt::from(1));
+    }
+
+    const SMALL_PRIMES: [u32; 15] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 41, 73, 97, 193, 241, 257];
+
+    #[test]
+    fn sqrt_and_legendre_match_brute_force_over_small_primes() {
+        for prime in SMALL_PRIMES {
+            let squares: Vec<u32> = (0..prime)
+                .map(|x| ((x as u64 * x as u64) % prime as u64) as u32)
+                .collect();
+            for value in 0..prime {
+                let element = U32FieldElement::new(value, prime);
+                let is_square = squares.contains(&value);
+                let expected_legendre = match (value, is_square) {
+                    (0, _) => 0,
+                    (_, true) => 1,
+                    (_, false) => -1,
+                };
+
+                assert_eq!(
+                    element.legendre(),
+                    expected_legendre,
+                    "{} mod {}",
+                    value,
+                    prime
+                );
+                match element.sqrt() {
+                    Some(root) => assert_eq!(root * root, element),
+                    None => assert!(!is_square, "{} mod {}", value, prime),
+                }
+                assert_eq!(element.sqrt().is_some(), is_square);
+            }
+        }
+    }
+
+    #[test]
+    fn tonelli_shanks_on_large_primes_congruent_to_1_mod_4() {
+        // 2^128 - 159 % 4 == 1
+        let element = FiniteFieldElement::<{ u128::MAX - 158 }>::from_u128(
+            0x1234_5678_9abc_def0_1234_5678_9abc_def0,
+        );
+        let square = element.clone() * element;
+        let root = square.sqrt().unwrap();
+        assert_eq!(root.clone() * root, square);
+
+        // The secp256k1 group order is congruent to 1 mod 4 as well
+        let order = &*crate::secp256k1::N;
+        let element = FieldElement::new(order - 12_345, order.clone());
+        let square = element.clone() * element;
+        let root = square.sqrt().unwrap();
+        assert_eq!(root.clone() * root, square);
+        assert_eq!(FieldElement::new(order - 1, order.clone()).legendre(), 1);
+    }
+
+    #[test]
+    fn legendre_symbol_of_known_values() {
+        assert_eq!(FiniteFieldElement::<223>::from(0).legendre(), 0);
+        assert_eq!(FiniteFieldElement::<223>::from(4).legendre(), 1);
+        assert_eq!(FiniteFieldElement::<223>::from(3).legendre(), -1);
+        assert_eq!(S256Field::from(7).legendre(), -1);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::field::{byte_length, mod_inverse, to_padded_bytes, Field};
+use num_bigint::{BigInt, BigUint, Sign};
+pub use programming_bitcoin::field_element::{is_probable_prime, FieldElement, PrimeModulus};
+
+impl Field for FieldElement {
+    fn zero(&self) -> Self {
+        Self::new(BigInt::from(0), self.prime().clone())
+    }
+
+    fn one(&self) -> Self {
+        Self::new(BigInt::from(1), self.prime().clone())
+    }
+
+    fn modulus(&self) -> BigUint {
+        self.prime().magnitude().clone()
+    }
+
+    fn element_from_bytes(&self, bytes: &[u8]) -> Option<Self> {
+        let num = BigInt::from_bytes_be(Sign::Plus, bytes);
+        (num < *self.prime()).then(|| Self::new(num, self.prime().clone()))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        to_padded_bytes(self.num().magnitude(), byte_length(&self.modulus()))
+    }
+
+    fn pow(&self, exponent: &BigUint) -> Self {
+        Self::new(
+            self.num()
+                .modpow(&BigInt::from(exponent.clone()), self.prime()),
+            self.prime().clone(),
+        )
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Some(Self::new(
+            mod_inverse(self.num(), self.prime())?,
+            self.prime().clone(),
+        ))
+    }
+}
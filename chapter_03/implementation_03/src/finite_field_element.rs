@@ -0,0 +1,58 @@
+use crate::field::{byte_length, to_padded_bytes, Field};
+pub use finite_field::finite_field_element::{is_prime, FiniteFieldElement};
+use num_bigint::BigUint;
+
+impl<const P: u128> Field for FiniteFieldElement<P> {
+    fn zero(&self) -> Self {
+        Self::from_u128(0)
+    }
+
+    fn one(&self) -> Self {
+        Self::from_u128(1)
+    }
+
+    fn modulus(&self) -> BigUint {
+        BigUint::from(P)
+    }
+
+    fn element_from_bytes(&self, bytes: &[u8]) -> Option<Self> {
+        let value = BigUint::from_bytes_be(bytes);
+        if value >= self.modulus() {
+            return None;
+        }
+        Some(Self::from_u128(u128::try_from(value).ok()?))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        to_padded_bytes(&BigUint::from(self.value()), byte_length(&self.modulus()))
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        FiniteFieldElement::inverse(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::DivisionByZeroError;
+
+    #[test]
+    fn test_division_by_zero_is_an_error() {
+        let one = FiniteFieldElement::<11>::from(1);
+        let zero = FiniteFieldElement::<11>::from(0);
+
+        assert_eq!(one.checked_div(&zero), Err(DivisionByZeroError));
+    }
+
+    #[test]
+    fn test_bytes_round_trip_near_the_u128_boundary() {
+        let element = FiniteFieldElement::<{ u128::MAX - 158 }>::from_u128(u128::MAX - 159);
+
+        assert_eq!(element.to_bytes(), (u128::MAX - 159).to_be_bytes());
+        assert_eq!(
+            element.element_from_bytes(&element.to_bytes()),
+            Some(element)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::errors::{IntegerOutOfRangeError, UnexpectedEndError, VarintError};
+use num_bigint::{BigInt, Sign};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// Double SHA-256 of `data`.
+pub fn hash256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// RIPEMD160 of the SHA-256 of `data`.
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// A hash256 digest kept in the byte order it is computed and serialized in.
+///
+/// It displays reversed, which is how txids and block hashes are shown by
+/// Bitcoin Core and block explorers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hash256([u8; 32]);
+
+impl Hash256 {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// hash256 of `data`.
+    pub fn of(data: &[u8]) -> Self {
+        Self(hash256(data))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for Hash256 {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl fmt::Display for Hash256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0.iter().rev() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits the first `length` bytes off `stream`.
+pub(crate) fn read_bytes<'a>(
+    stream: &mut &'a [u8],
+    length: usize,
+) -> Result<&'a [u8], UnexpectedEndError> {
+    if stream.len() < length {
+        return Err(UnexpectedEndError);
+    }
+    let (head, tail) = stream.split_at(length);
+    *stream = tail;
+    Ok(head)
+}
+
+/// Reads a CompactSize integer off `stream`: values below 0xfd are a single
+/// byte, larger ones follow a 0xfd, 0xfe or 0xff marker as 2, 4 or 8
+/// little-endian bytes.
+///
+/// Like Bitcoin Core, values that fit a shorter form are rejected, so every
+/// value has exactly one encoding.
+pub fn read_varint(stream: &mut &[u8]) -> Result<u64, VarintError> {
+    let (length, minimum) = match read_bytes(stream, 1)?[0] {
+        0xfd => (2, 0xfd),
+        0xfe => (4, 0x1_0000),
+        0xff => (8, 0x1_0000_0000),
+        value => return Ok(value as u64),
+    };
+    let mut bytes = [0_u8; 8];
+    bytes[..length].copy_from_slice(read_bytes(stream, length)?);
+    match u64::from_le_bytes(bytes) {
+        value if value < minimum => Err(VarintError::NonCanonical(value)),
+        value => Ok(value),
+    }
+}
+
+/// CompactSize encoding of `value`, using the shortest form.
+pub fn encode_varint(value: u64) -> Vec<u8> {
+    match value {
+        0..=0xfc => vec![value as u8],
+        0xfd..=0xffff => [&[0xfd], &(value as u16).to_le_bytes()[..]].concat(),
+        0x10000..=0xffff_ffff => [&[0xfe], &(value as u32).to_le_bytes()[..]].concat(),
+        _ => [&[0xff], &value.to_le_bytes()[..]].concat(),
+    }
+}
+
+/// Interprets `bytes` as an unsigned little-endian integer.
+pub fn little_endian_to_int(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_le(Sign::Plus, bytes)
+}
+
+/// Interprets `bytes` as an unsigned big-endian integer.
+pub fn big_endian_to_int(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, bytes)
+}
+
+/// Little-endian encoding of `value`, right padded with zeros to `length`
+/// bytes, failing if it is negative or does not fit.
+pub fn int_to_little_endian(
+    value: &BigInt,
+    length: usize,
+) -> Result<Vec<u8>, IntegerOutOfRangeError> {
+    let (sign, mut bytes) = value.to_bytes_le();
+    if sign == Sign::Minus || value.bits() > length as u64 * 8 {
+        return Err(IntegerOutOfRangeError);
+    }
+    bytes.resize(length, 0);
+    Ok(bytes)
+}
+
+/// Big-endian encoding of `value`, left padded with zeros to `length`
+/// bytes, failing if it is negative or does not fit.
+pub fn int_to_big_endian(value: &BigInt, length: usize) -> Result<Vec<u8>, IntegerOutOfRangeError> {
+    let mut bytes = int_to_little_endian(value, length)?;
+    bytes.reverse();
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secp256k1::from_hex;
+    use proptest::prelude::*;
+
+    #[test]
+    fn hash256_and_hash160_of_an_empty_input() {
+        assert_eq!(
+            hex::encode(hash256(&[])),
+            "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
+        );
+        assert_eq!(
+            hex::encode(hash160(&[])),
+            "b472a266d0bd89c13706a4132ccfb16f7c3b9fcb"
+        );
+    }
+
+    #[test]
+    fn hash256_displays_in_reversed_byte_order() {
+        let mut bytes = [0_u8; 32];
+        bytes[0] = 0x01;
+        bytes[31] = 0xab;
+        let hash = Hash256::new(bytes);
+
+        assert_eq!(
+            hash.to_string(),
+            "ab00000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(Hash256::of(b"data").as_bytes(), &hash256(b"data"));
+    }
+
+    #[test]
+    fn varint_boundaries() {
+        let vectors = [
+            (0, "00"),
+            (0xfc, "fc"),
+            (0xfd, "fdfd00"),
+            (0xffff, "fdffff"),
+            (0x10000, "fe00000100"),
+            (0xffff_ffff, "feffffffff"),
+            (0x1_0000_0000, "ff0000000001000000"),
+        ];
+        for (value, encoded) in vectors {
+            let encoded = hex::decode(encoded).unwrap();
+            let mut stream = &encoded[..];
+
+            assert_eq!(encode_varint(value), encoded);
+            assert_eq!(read_varint(&mut stream), Ok(value));
+            assert!(stream.is_empty());
+        }
+    }
+
+    #[test]
+    fn truncated_varints_are_rejected() {
+        for encoded in ["", "fd00", "fe000000", "ff00000000000000"] {
+            let encoded = hex::decode(encoded).unwrap();
+
+            assert_eq!(
+                read_varint(&mut &encoded[..]),
+                Err(VarintError::UnexpectedEnd)
+            );
+        }
+    }
+
+    #[test]
+    fn non_canonical_varints_are_rejected() {
+        let vectors = [
+            ("fdfc00", 0xfc),
+            ("feffff0000", 0xffff),
+            ("ffffffffff00000000", 0xffff_ffff),
+        ];
+        for (encoded, value) in vectors {
+            let encoded = hex::decode(encoded).unwrap();
+
+            assert_eq!(
+                read_varint(&mut &encoded[..]),
+                Err(VarintError::NonCanonical(value))
+            );
+        }
+    }
+
+    #[test]
+    fn endian_conversions() {
+        let value = from_hex("0102030405");
+
+        assert_eq!(
+            int_to_big_endian(&value, 8),
+            Ok(hex::decode("0000000102030405").unwrap())
+        );
+        assert_eq!(
+            int_to_little_endian(&value, 8),
+            Ok(hex::decode("0504030201000000").unwrap())
+        );
+        assert_eq!(
+            big_endian_to_int(&hex::decode("0102030405").unwrap()),
+            value
+        );
+        assert_eq!(
+            little_endian_to_int(&hex::decode("0504030201").unwrap()),
+            value
+        );
+        assert_eq!(int_to_big_endian(&BigInt::from(0), 2), Ok(vec![0, 0]));
+    }
+
+    #[test]
+    fn values_that_do_not_fit_are_rejected() {
+        for value in [BigInt::from(0x10000), BigInt::from(-1)] {
+            assert_eq!(int_to_big_endian(&value, 2), Err(IntegerOutOfRangeError));
+            assert_eq!(int_to_little_endian(&value, 2), Err(IntegerOutOfRangeError));
+        }
+        assert_eq!(
+            int_to_big_endian(&BigInt::from(0xffff), 2),
+            Ok(vec![0xff, 0xff])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn varint_round_trip(value in any::<u64>()) {
+            let encoded = encode_varint(value);
+
+            prop_assert_eq!(read_varint(&mut &encoded[..]), Ok(value));
+        }
+
+        #[test]
+        fn endian_round_trip(bytes in proptest::collection::vec(any::<u8>(), 0..40)) {
+            let length = bytes.len();
+
+            prop_assert_eq!(int_to_big_endian(&big_endian_to_int(&bytes), length), Ok(bytes.clone()));
+            prop_assert_eq!(int_to_little_endian(&little_endian_to_int(&bytes), length), Ok(bytes));
+        }
+    }
+}
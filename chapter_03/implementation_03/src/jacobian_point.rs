@@ -0,0 +1,677 @@
+use crate::curve::Curve;
+use crate::field::{batch_invert, Field};
+use crate::point::Point;
+use num_bigint::{BigInt, Sign};
+use std::ops::Add;
+
+/// Window width used by `JacobianPoint::mul_wnaf`.
+const WNAF_WIDTH: u32 = 4;
+
+/// Number of terms from which `JacobianPoint::multi_mul` uses Pippenger's
+/// method.
+const PIPPENGER_THRESHOLD: usize = 64;
+
+/// A point on the curve `C` in Jacobian coordinates, where (X, Y, Z) stands
+/// for the affine point (X / Z^2, Y / Z^3) and Z == 0 for the point at
+/// infinity.
+///
+/// Additions and doublings need no field inversion; only the conversion back
+/// to an affine `Point` does.
+#[derive(Clone, Debug)]
+pub struct JacobianPoint<C: Curve> {
+    x: C::Field,
+    y: C::Field,
+    z: C::Field,
+}
+
+impl<C: Curve> JacobianPoint<C> {
+    pub fn new_infinity() -> Self {
+        Self {
+            x: C::Field::from(1),
+            y: C::Field::from(1),
+            z: C::Field::from(0),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Affine form of the point, at the cost of one field inversion.
+    pub fn to_affine(&self) -> Point<C> {
+        match self.z.inverse() {
+            Some(z_inverse) => self.scale(z_inverse),
+            None => Point::new_infinity(),
+        }
+    }
+
+    /// Affine forms of all `points` sharing a single field inversion.
+    pub fn batch_to_affine(points: &[Self]) -> Vec<Point<C>> {
+        let mut z_inverses: Vec<C::Field> = points
+            .iter()
+            .filter(|point| !point.is_infinity())
+            .map(|point| point.z.clone())
+            .collect();
+        batch_invert(&mut z_inverses).expect("Only finite points are inverted");
+        let mut z_inverses = z_inverses.into_iter();
+        points
+            .iter()
+            .map(|point| match point.is_infinity() {
+                true => Point::new_infinity(),
+                false => point.scale(z_inverses.next().unwrap()),
+            })
+            .collect()
+    }
+
+    fn scale(&self, z_inverse: C::Field) -> Point<C> {
+        let z_inverse_squared = z_inverse.clone() * z_inverse.clone();
+        let x = self.x.clone() * z_inverse_squared.clone();
+        let y = self.y.clone() * z_inverse_squared * z_inverse;
+        Point::Point(x, y)
+    }
+
+    pub fn negate(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: self.y.zero() - self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+
+    /// Doubles the point ("dbl-2009-l" from the Explicit-Formulas Database,
+    /// with the a·Z^4 term added back when a != 0).
+    pub fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::new_infinity();
+        }
+        let a = self.x.clone() * self.x.clone();
+        let b = self.y.clone() * self.y.clone();
+        let c = b.clone() * b.clone();
+        let x_plus_b = self.x.clone() + b;
+        let d = C::Field::from(2) * (x_plus_b.clone() * x_plus_b - a.clone() - c.clone());
+        let mut e = C::Field::from(3) * a;
+        if !C::a().is_zero() {
+            let zz = self.z.clone() * self.z.clone();
+            e = e + C::a() * zz.clone() * zz;
+        }
+        let f = e.clone() * e.clone();
+
+        let x3 = f - C::Field::from(2) * d.clone();
+        let y3 = e * (d - x3.clone()) - C::Field::from(8) * c;
+        let z3 = C::Field::from(2) * self.y.clone() * self.z.clone();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Adds two Jacobian points ("add-2007-bl").
+    pub fn add_jacobian(&self, other: &Self) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+        let z1z1 = self.z.clone() * self.z.clone();
+        let z2z2 = other.z.clone() * other.z.clone();
+        let u1 = self.x.clone() * z2z2.clone();
+        let u2 = other.x.clone() * z1z1.clone();
+        let s1 = self.y.clone() * other.z.clone() * z2z2.clone();
+        let s2 = other.y.clone() * self.z.clone() * z1z1.clone();
+        let h = u2 - u1.clone();
+        if h.is_zero() {
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::new_infinity()
+            };
+        }
+        let two_h = C::Field::from(2) * h.clone();
+        let i = two_h.clone() * two_h;
+        let j = h.clone() * i.clone();
+        let r = C::Field::from(2) * (s2 - s1.clone());
+        let v = u1 * i;
+
+        let x3 = r.clone() * r.clone() - j.clone() - C::Field::from(2) * v.clone();
+        let y3 = r * (v - x3.clone()) - C::Field::from(2) * s1 * j;
+        let z1_plus_z2 = self.z.clone() + other.z.clone();
+        let z3 = (z1_plus_z2.clone() * z1_plus_z2 - z1z1 - z2z2) * h;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Adds an affine point, which saves several multiplications since its
+    /// Z is 1 ("madd-2007-bl").
+    pub fn add_affine(&self, other: &Point<C>) -> Self {
+        let Point::Point(x2, y2) = other else {
+            return self.clone();
+        };
+        if self.is_infinity() {
+            return Self::from(other.clone());
+        }
+        let z1z1 = self.z.clone() * self.z.clone();
+        let u2 = x2.clone() * z1z1.clone();
+        let s2 = y2.clone() * self.z.clone() * z1z1.clone();
+        let h = u2 - self.x.clone();
+        if h.is_zero() {
+            return if s2 == self.y {
+                self.double()
+            } else {
+                Self::new_infinity()
+            };
+        }
+        let hh = h.clone() * h.clone();
+        let i = C::Field::from(4) * hh.clone();
+        let j = h.clone() * i.clone();
+        let r = C::Field::from(2) * (s2 - self.y.clone());
+        let v = self.x.clone() * i;
+
+        let x3 = r.clone() * r.clone() - j.clone() - C::Field::from(2) * v.clone();
+        let y3 = r * (v - x3.clone()) - C::Field::from(2) * self.y.clone() * j;
+        let z1_plus_h = self.z.clone() + h;
+        let z3 = z1_plus_h.clone() * z1_plus_h - z1z1 - hh;
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Computes k·P with double-and-add, converting back to affine once.
+    pub fn scalar_mul(point: &Point<C>, k: &BigInt) -> Point<C> {
+        if k.sign() == Sign::Minus {
+            return Self::scalar_mul(&point.negate(), &-k);
+        }
+        let mut result = Self::new_infinity();
+        for i in (0..k.bits()).rev() {
+            result = result.double();
+            if k.bit(i) {
+                result = result.add_affine(point);
+            }
+        }
+        result.to_affine()
+    }
+
+    /// Computes k·P from the width-4 non-adjacent form of `k`, which needs
+    /// an addition for only about a fifth of the bits, using the odd
+    /// multiples P, 3P, 5P and 7P.
+    pub fn mul_wnaf(point: &Point<C>, k: &BigInt) -> Point<C> {
+        Self::multi_mul(&[(k.clone(), point.clone())])
+    }
+
+    /// Computes the sum of k·P over all `terms`.
+    ///
+    /// Small inputs interleave the wNAF multiplications of every term so they
+    /// share the doublings (Strauss-Shamir); from `PIPPENGER_THRESHOLD` terms
+    /// on, Pippenger's bucket method is used instead.
+    pub fn multi_mul(terms: &[(BigInt, Point<C>)]) -> Point<C> {
+        let terms = with_non_negative_scalars(terms);
+        let result = if terms.len() < PIPPENGER_THRESHOLD {
+            Self::strauss(&terms)
+        } else {
+            Self::pippenger(&terms)
+        };
+        result.to_affine()
+    }
+
+    /// Computes the sum of k·P over every group of terms in `batch` with
+    /// Strauss-Shamir, converting the precomputed multiples of all groups to
+    /// affine with a single field inversion.
+    ///
+    /// The sums are left in Jacobian coordinates, so no other inversion is
+    /// needed.
+    pub fn multi_mul_batch(batch: &[Vec<(BigInt, Point<C>)>]) -> Vec<Self> {
+        let batch: Vec<_> = batch
+            .iter()
+            .map(|terms| with_non_negative_scalars(terms))
+            .collect();
+        let odd_multiples: Vec<_> = batch
+            .iter()
+            .flat_map(|terms| Self::odd_multiples(terms))
+            .collect();
+        let mut odd_multiples = &Self::batch_to_affine(&odd_multiples)[..];
+        batch
+            .iter()
+            .map(|terms| {
+                let (table, rest) = odd_multiples.split_at(terms.len() << (WNAF_WIDTH - 2));
+                odd_multiples = rest;
+                Self::interleave(terms, table)
+            })
+            .collect()
+    }
+
+    /// Whether the affine x-coordinate of the point is `x`, without the
+    /// inversion `to_affine` needs.
+    pub fn has_affine_x(&self, x: &C::Field) -> bool {
+        !self.is_infinity() && self.x == x.clone() * self.z.clone() * self.z.clone()
+    }
+
+    /// Strauss-Shamir multi-scalar multiplication for non-negative scalars.
+    fn strauss(terms: &[(BigInt, Point<C>)]) -> Self {
+        let odd_multiples = Self::batch_to_affine(&Self::odd_multiples(terms));
+        Self::interleave(terms, &odd_multiples)
+    }
+
+    /// P, 3P, 5P and 7P for the point of every term, in order.
+    fn odd_multiples(terms: &[(BigInt, Point<C>)]) -> Vec<Self> {
+        let table_length = 1 << (WNAF_WIDTH - 2);
+        let mut odd_multiples = Vec::with_capacity(terms.len() * table_length);
+        for (_, point) in terms {
+            let base = Self::from(point.clone());
+            let double = base.double();
+            odd_multiples.push(base);
+            for _ in 1..table_length {
+                let next = odd_multiples[odd_multiples.len() - 1].add_jacobian(&double);
+                odd_multiples.push(next);
+            }
+        }
+        odd_multiples
+    }
+
+    /// Adds up the wNAF multiplications of all `terms` with shared doublings,
+    /// given the affine `odd_multiples` of their points.
+    fn interleave(terms: &[(BigInt, Point<C>)], odd_multiples: &[Point<C>]) -> Self {
+        let table_length = 1 << (WNAF_WIDTH - 2);
+        let digits: Vec<_> = terms.iter().map(|(k, _)| wnaf(k, WNAF_WIDTH)).collect();
+        let length = digits.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut result = Self::new_infinity();
+        for i in (0..length).rev() {
+            result = result.double();
+            for (term, term_digits) in digits.iter().enumerate() {
+                let digit = term_digits.get(i).copied().unwrap_or(0);
+                let multiple =
+                    &odd_multiples[term * table_length + (digit.unsigned_abs() / 2) as usize];
+                if digit > 0 {
+                    result = result.add_affine(multiple);
+                } else if digit < 0 {
+                    result = result.add_affine(&multiple.negate());
+                }
+            }
+        }
+        result
+    }
+
+    /// Pippenger's bucket method for non-negative scalars: for every window
+    /// of the scalars, points are added into one bucket per digit value and
+    /// the buckets are combined with two running sums.
+    fn pippenger(terms: &[(BigInt, Point<C>)]) -> Self {
+        let window = (terms.len().ilog2() as usize).max(2);
+        let digit_mask = BigInt::from((1 << window) - 1);
+        let bits = terms.iter().map(|(k, _)| k.bits()).max().unwrap_or(0) as usize;
+
+        let mut result = Self::new_infinity();
+        for w in (0..bits.div_ceil(window)).rev() {
+            for _ in 0..window {
+                result = result.double();
+            }
+            let mut buckets = vec![Self::new_infinity(); (1 << window) - 1];
+            for (k, point) in terms {
+                let digit: usize = ((k >> (w * window)) & &digit_mask).try_into().unwrap();
+                if digit != 0 {
+                    buckets[digit - 1] = buckets[digit - 1].add_affine(point);
+                }
+            }
+            // Summing the running sums adds each bucket as many times as its digit
+            let mut running_sum = Self::new_infinity();
+            let mut window_sum = Self::new_infinity();
+            for bucket in buckets.iter().rev() {
+                running_sum = running_sum.add_jacobian(bucket);
+                window_sum = window_sum.add_jacobian(&running_sum);
+            }
+            result = result.add_jacobian(&window_sum);
+        }
+        result
+    }
+}
+
+/// `terms` with negative scalars turned into positive ones on the negated
+/// point.
+fn with_non_negative_scalars<C: Curve>(terms: &[(BigInt, Point<C>)]) -> Vec<(BigInt, Point<C>)> {
+    terms
+        .iter()
+        .map(|(k, point)| match k.sign() {
+            Sign::Minus => (-k, point.negate()),
+            _ => (k.clone(), point.clone()),
+        })
+        .collect()
+}
+
+/// Width-`width` non-adjacent form of a non-negative `k`, least significant
+/// digit first: every non-zero digit is odd, below 2^(width - 1) in absolute
+/// value and followed by at least `width - 1` zeros.
+fn wnaf(k: &BigInt, width: u32) -> Vec<i8> {
+    let window = 1_i64 << width;
+    let mut k = k.clone();
+    let mut digits = Vec::with_capacity(k.bits() as usize + 1);
+    while k.sign() == Sign::Plus {
+        let mut digit = 0;
+        if k.bit(0) {
+            let low_bits = (&k & BigInt::from(window - 1)).try_into().unwrap_or(0_i64);
+            digit = if low_bits >= window / 2 {
+                low_bits - window
+            } else {
+                low_bits
+            };
+            k -= digit;
+        }
+        digits.push(digit as i8);
+        k >>= 1;
+    }
+    digits
+}
+
+impl<C: Curve> From<Point<C>> for JacobianPoint<C> {
+    fn from(point: Point<C>) -> Self {
+        match point {
+            Point::Point(x, y) => Self { z: x.one(), x, y },
+            Point::Infinity => Self::new_infinity(),
+        }
+    }
+}
+
+impl<C: Curve> From<JacobianPoint<C>> for Point<C> {
+    fn from(point: JacobianPoint<C>) -> Self {
+        point.to_affine()
+    }
+}
+
+impl<C: Curve> PartialEq for JacobianPoint<C> {
+    /// Compares the represented affine points, so different Z scalings of
+    /// the same point are equal.
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_infinity(), other.is_infinity()) {
+            (true, true) => true,
+            (false, false) => {
+                let z1z1 = self.z.clone() * self.z.clone();
+                let z2z2 = other.z.clone() * other.z.clone();
+                self.x.clone() * z2z2.clone() == other.x.clone() * z1z1.clone()
+                    && self.y.clone() * z2z2 * other.z.clone()
+                        == other.y.clone() * z1z1 * self.z.clone()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C: Curve> Add for JacobianPoint<C> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.add_jacobian(&other)
+    }
+}
+
+impl<C: Curve> Add<Point<C>> for JacobianPoint<C> {
+    type Output = Self;
+    fn add(self, other: Point<C>) -> Self {
+        self.add_affine(&other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secp256k1::{S256Point, G, N};
+    use proptest::prelude::*;
+
+    type S256Jacobian = JacobianPoint<crate::secp256k1::Secp256k1>;
+
+    fn multiple(k: u64) -> S256Point {
+        G.scalar_mul(&BigInt::from(k))
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let point = multiple(12_345);
+
+        assert_eq!(S256Jacobian::from(point.clone()).to_affine(), point);
+        assert_eq!(
+            S256Point::from(S256Jacobian::from(S256Point::new_infinity())),
+            S256Point::new_infinity()
+        );
+    }
+
+    #[test]
+    fn batch_conversion_matches_single_conversions() {
+        let mut points = vec![S256Jacobian::new_infinity()];
+        for k in 1..10 {
+            points.push(points[k - 1].clone().add_affine(&G));
+        }
+        let expected: Vec<_> = points.iter().map(|point| point.to_affine()).collect();
+
+        assert_eq!(S256Jacobian::batch_to_affine(&points), expected);
+        assert_eq!(expected[9], multiple(9));
+    }
+
+    #[test]
+    fn double_matches_affine_doubling() {
+        let point = multiple(7);
+        let doubled = S256Jacobian::from(point.clone()).double();
+
+        assert_eq!(doubled.to_affine(), point.clone() + point.clone());
+        assert_eq!(doubled.double().to_affine(), multiple(28));
+    }
+
+    #[test]
+    fn additions_match_affine_additions() {
+        let p = multiple(3);
+        let q = multiple(1_000_003);
+        // Z != 1 on both sides
+        let p_jacobian = S256Jacobian::from(p.clone()).double();
+        let q_jacobian = S256Jacobian::from(q.clone()).double();
+
+        assert_eq!(
+            (p_jacobian.clone() + q_jacobian.clone()).to_affine(),
+            multiple(2 * 3 + 2 * 1_000_003)
+        );
+        assert_eq!(
+            (p_jacobian.clone() + q.clone()).to_affine(),
+            multiple(6) + q.clone()
+        );
+        assert_eq!(p_jacobian.clone() + p_jacobian.clone(), p_jacobian.double());
+        assert_eq!((p_jacobian.clone() + multiple(6)).to_affine(), multiple(12));
+    }
+
+    #[test]
+    fn infinity_cases() {
+        let p = multiple(5);
+        let p_jacobian = S256Jacobian::from(p.clone());
+        let infinity = S256Jacobian::new_infinity();
+
+        assert!((p_jacobian.clone() + p_jacobian.negate()).is_infinity());
+        assert!((p_jacobian.clone() + p.negate()).is_infinity());
+        assert_eq!(infinity.clone() + p_jacobian.clone(), p_jacobian);
+        assert_eq!(p_jacobian.clone() + infinity.clone(), p_jacobian);
+        assert_eq!(infinity.clone() + p.clone(), p_jacobian);
+        assert_eq!(p_jacobian.clone() + S256Point::new_infinity(), p_jacobian);
+        assert!(infinity.double().is_infinity());
+        assert_ne!(infinity, p_jacobian);
+    }
+
+    #[test]
+    fn scalar_mul_matches_affine_scalar_mul() {
+        for k in [
+            BigInt::from(0),
+            BigInt::from(1),
+            BigInt::from(-2),
+            &*N - 1,
+            N.clone(),
+        ] {
+            assert_eq!(S256Jacobian::scalar_mul(&G, &k), G.scalar_mul(&k));
+        }
+    }
+
+    type F97 = crate::finite_field_element::FiniteFieldElement<97>;
+
+    /// y^2 = x^3 + 2x + 3 over the field of order 97, to cover a != 0.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct F97Curve;
+
+    impl Curve for F97Curve {
+        type Field = F97;
+
+        fn a() -> Self::Field {
+            Self::Field::from(2)
+        }
+
+        fn b() -> Self::Field {
+            Self::Field::from(3)
+        }
+
+        fn generator() -> Point<Self> {
+            Point::new_point(Self::Field::from(3), Self::Field::from(6)).unwrap()
+        }
+
+        fn order() -> BigInt {
+            BigInt::from(5)
+        }
+
+        fn cofactor() -> BigInt {
+            BigInt::from(20)
+        }
+    }
+
+    #[test]
+    fn curves_with_non_zero_a_match_affine_arithmetic() {
+        // (0, 10) has order 50
+        let point = Point::<F97Curve>::new_point(F97::from(0), F97::from(10)).unwrap();
+        for k in -51..=51 {
+            let k = BigInt::from(k);
+            let expected = point.scalar_mul(&k);
+            assert_eq!(JacobianPoint::scalar_mul(&point, &k), expected);
+            assert_eq!(point.mul_wnaf(&k), expected);
+            assert_eq!(
+                Point::multi_mul(&[
+                    (k.clone(), point.clone()),
+                    (k.clone(), F97Curve::generator())
+                ]),
+                expected + F97Curve::generator().scalar_mul(&k)
+            );
+        }
+    }
+
+    #[test]
+    fn wnaf_digits_rebuild_the_scalar() {
+        for k in [0_u64, 1, 7, 8, 15, 255, 1_000_003, u64::MAX] {
+            let digits = wnaf(&BigInt::from(k), 4);
+            let mut rebuilt = BigInt::from(0);
+            for (i, digit) in digits.iter().enumerate() {
+                rebuilt += BigInt::from(*digit) << i;
+                if *digit != 0 {
+                    assert!(digit % 2 != 0 && digit.abs() < 8);
+                    assert!(digits.iter().skip(i + 1).take(3).all(|d| *d == 0));
+                }
+            }
+            assert_eq!(rebuilt, BigInt::from(k));
+        }
+    }
+
+    fn naive_sum(terms: &[(BigInt, S256Point)]) -> S256Point {
+        terms
+            .iter()
+            .fold(S256Point::new_infinity(), |sum, (k, point)| {
+                sum + point.scalar_mul(k)
+            })
+    }
+
+    #[test]
+    fn multi_mul_edge_cases() {
+        let p = multiple(5);
+
+        assert_eq!(S256Jacobian::multi_mul(&[]), S256Point::new_infinity());
+        assert_eq!(
+            S256Jacobian::multi_mul(&[(BigInt::from(3), p.clone()), (BigInt::from(-3), p.clone())]),
+            S256Point::new_infinity()
+        );
+        assert_eq!(
+            S256Jacobian::multi_mul(&[
+                (BigInt::from(0), p.clone()),
+                (BigInt::from(2), S256Point::new_infinity()),
+                (N.clone(), p.clone()),
+            ]),
+            S256Point::new_infinity()
+        );
+    }
+
+    #[test]
+    fn multi_mul_batch_matches_separate_multi_muls() {
+        let batch = vec![
+            vec![
+                (BigInt::from(7), G.clone()),
+                (BigInt::from(-11), multiple(3)),
+            ],
+            vec![],
+            vec![(&*N - 5, multiple(9))],
+            vec![
+                (BigInt::from(2), S256Point::new_infinity()),
+                (BigInt::from(4), multiple(2)),
+                (BigInt::from(-8), G.clone()),
+            ],
+        ];
+        let sums = S256Jacobian::multi_mul_batch(&batch);
+
+        assert_eq!(sums.len(), batch.len());
+        for (sum, terms) in sums.iter().zip(&batch) {
+            assert_eq!(sum.to_affine(), naive_sum(terms));
+        }
+    }
+
+    #[test]
+    fn has_affine_x_compares_without_converting() {
+        let p = S256Jacobian::from(multiple(5)).double();
+        let affine = p.to_affine();
+
+        assert!(p.has_affine_x(affine.x().unwrap()));
+        assert!(!p.has_affine_x(G.x().unwrap()));
+        assert!(!S256Jacobian::new_infinity().has_affine_x(G.x().unwrap()));
+    }
+
+    #[test]
+    fn pippenger_matches_strauss_and_naive_summation() {
+        let terms: Vec<_> = (1..=PIPPENGER_THRESHOLD as u64 + 6)
+            .map(|i| {
+                let k =
+                    BigInt::from(i.wrapping_mul(0x9e37_79b9_7f4a_7c15)) - BigInt::from(1_u64 << 40);
+                (k, multiple(i * 31))
+            })
+            .collect();
+        let expected = naive_sum(&terms);
+
+        assert_eq!(S256Jacobian::multi_mul(&terms), expected);
+        let non_negative = with_non_negative_scalars(&terms);
+        assert_eq!(S256Jacobian::strauss(&non_negative).to_affine(), expected);
+        assert_eq!(S256Jacobian::pippenger(&non_negative).to_affine(), expected);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn random_scalar_muls_match_affine(k in any::<[u8; 32]>()) {
+            let k = BigInt::from_bytes_be(Sign::Plus, &k);
+            prop_assert_eq!(S256Jacobian::scalar_mul(&G, &k), G.scalar_mul(&k));
+            prop_assert_eq!(S256Jacobian::mul_wnaf(&G, &k), G.scalar_mul(&k));
+        }
+
+        #[test]
+        fn random_multi_muls_match_naive_summation(
+            scalars in prop::collection::vec(any::<[u8; 32]>(), 1..4),
+            negate in any::<bool>(),
+        ) {
+            let terms: Vec<_> = scalars
+                .iter()
+                .enumerate()
+                .map(|(i, k)| {
+                    let k = BigInt::from_bytes_be(Sign::Plus, k);
+                    (if negate { -k } else { k }, multiple(i as u64 + 2))
+                })
+                .collect();
+            prop_assert_eq!(S256Jacobian::multi_mul(&terms), naive_sum(&terms));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+pub mod address;
+pub mod base58;
+pub mod curve;
+pub mod errors;
+pub mod field;
+pub mod field_element;
+pub mod finite_field_element;
+pub mod helpers;
+pub mod jacobian_point;
+pub mod montgomery_field;
+pub mod network;
+pub mod point;
+pub mod private_key;
+pub mod projective_point;
+pub mod public_key;
+pub mod rfc6979;
+pub mod script;
+pub mod secp256k1;
+pub mod signature;
+pub mod tx;
+pub mod u32_field_element;
@@ -0,0 +1,555 @@
+use crate::field::Field;
+use num_bigint::BigUint;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Odd 256-bit modulus for `MontgomeryFieldElement`, stored as little-endian
+/// 64-bit limbs together with the constants Montgomery arithmetic needs.
+pub trait Modulus: Clone + Copy + fmt::Debug + PartialEq + Eq {
+    const MODULUS: [u64; 4];
+    /// R^2 mod MODULUS, where R = 2^256.
+    const R2: [u64; 4];
+    /// -MODULUS^-1 mod 2^64.
+    const INV: u64;
+
+    /// Computes t * R^-1 mod MODULUS for any t < MODULUS * R.
+    fn reduce(t: [u64; 8]) -> [u64; 4] {
+        let mut t = widen(t);
+        for i in 0..4 {
+            let m = t[i].wrapping_mul(Self::INV);
+            let mut carry = 0_u128;
+            for j in 0..4 {
+                let value = t[i + j] as u128 + m as u128 * Self::MODULUS[j] as u128 + carry;
+                t[i + j] = value as u64;
+                carry = value >> 64;
+            }
+            add_at(&mut t, i + 4, carry);
+        }
+        finish_reduction::<Self>(t)
+    }
+}
+
+/// Prime of the field secp256k1 is defined over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1Modulus;
+
+/// 2^256 - P for the secp256k1 prime.
+const SECP256K1_C: u64 = 0x1000003d1;
+
+impl Modulus for Secp256k1Modulus {
+    const MODULUS: [u64; 4] = [
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+    const R2: [u64; 4] = [0x000007a2000e90a1, 0x0000000000000001, 0, 0];
+    const INV: u64 = 0xd838091dd2253531;
+
+    /// Since P = 2^256 - C, adding m * P is the same as adding m * 2^256 and
+    /// subtracting m * C, which avoids multiplying by every limb of P.
+    fn reduce(t: [u64; 8]) -> [u64; 4] {
+        let mut t = widen(t);
+        for i in 0..4 {
+            let m = t[i].wrapping_mul(Self::INV);
+            add_at(&mut t, i + 4, m as u128);
+            sub_at(&mut t, i, m as u128 * SECP256K1_C as u128);
+        }
+        finish_reduction::<Self>(t)
+    }
+}
+
+/// Order of the secp256k1 group, for arithmetic on scalars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1Order;
+
+impl Modulus for Secp256k1Order {
+    const MODULUS: [u64; 4] = [
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+    ];
+    const R2: [u64; 4] = [
+        0x896cf21467d7d140,
+        0x741496c20e7cf878,
+        0xe697f5e45bcd07c6,
+        0x9d671cd581c69bc5,
+    ];
+    const INV: u64 = 0x4b0dff665588b13f;
+}
+
+fn widen(t: [u64; 8]) -> [u64; 9] {
+    let mut wide = [0_u64; 9];
+    wide[..8].copy_from_slice(&t);
+    wide
+}
+
+fn add_at(t: &mut [u64; 9], position: usize, value: u128) {
+    let mut carry = value;
+    for limb in t.iter_mut().skip(position) {
+        let sum = *limb as u128 + (carry as u64) as u128;
+        *limb = sum as u64;
+        carry = (carry >> 64) + (sum >> 64);
+    }
+}
+
+fn sub_at(t: &mut [u64; 9], position: usize, value: u128) {
+    let mut borrow = value;
+    for limb in t.iter_mut().skip(position) {
+        let (difference, underflow) = limb.overflowing_sub(borrow as u64);
+        *limb = difference;
+        borrow = (borrow >> 64) + underflow as u128;
+    }
+}
+
+fn finish_reduction<M: Modulus>(t: [u64; 9]) -> [u64; 4] {
+    let result = [t[4], t[5], t[6], t[7]];
+    let (reduced, borrow) = sub_limbs(&result, &M::MODULUS);
+    select(mask((t[8] != 0) | !borrow), &reduced, &result)
+}
+
+/// All ones when `flag` is set, zero otherwise.
+fn mask(flag: bool) -> u64 {
+    0_u64.wrapping_sub(flag as u64)
+}
+
+/// `a` where `mask` is all ones and `b` where it is zero, without branching.
+fn select(mask: u64, a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    core::array::from_fn(|i| (a[i] & mask) | (b[i] & !mask))
+}
+
+/// Whether `a < b`, read off the borrow of `a - b`.
+fn less_than(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    sub_limbs(a, b).1
+}
+
+fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut result = [0_u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, overflow1) = a[i].overflowing_add(b[i]);
+        let (sum, overflow2) = sum.overflowing_add(carry as u64);
+        result[i] = sum;
+        carry = overflow1 | overflow2;
+    }
+    (result, carry)
+}
+
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut result = [0_u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (difference, underflow1) = a[i].overflowing_sub(b[i]);
+        let (difference, underflow2) = difference.overflowing_sub(borrow as u64);
+        result[i] = difference;
+        borrow = underflow1 | underflow2;
+    }
+    (result, borrow)
+}
+
+fn mul_wide(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    let mut result = [0_u64; 8];
+    for i in 0..4 {
+        let mut carry = 0_u128;
+        for j in 0..4 {
+            let value = result[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry;
+            result[i + j] = value as u64;
+            carry = value >> 64;
+        }
+        result[i + 4] = carry as u64;
+    }
+    result
+}
+
+/// Element of the prime field defined by `M`, held in Montgomery form as four
+/// 64-bit limbs, so no operation allocates.
+///
+/// Addition, subtraction and multiplication do the same limb operations
+/// whatever the values, selecting their final reduction with masks instead
+/// of branches, so they can handle secrets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontgomeryFieldElement<M: Modulus> {
+    limbs: [u64; 4],
+    modulus: PhantomData<M>,
+}
+
+/// secp256k1 field element backed by fixed-width Montgomery arithmetic.
+pub type S256MontgomeryField = MontgomeryFieldElement<Secp256k1Modulus>;
+
+/// Integer modulo the order of the secp256k1 group, backed by fixed-width
+/// Montgomery arithmetic.
+pub type S256Scalar = MontgomeryFieldElement<Secp256k1Order>;
+
+impl<M: Modulus> MontgomeryFieldElement<M> {
+    fn from_montgomery(limbs: [u64; 4]) -> Self {
+        Self {
+            limbs,
+            modulus: PhantomData,
+        }
+    }
+
+    /// Builds an element from little-endian limbs, which must be below the modulus.
+    pub fn from_limbs(limbs: [u64; 4]) -> Option<Self> {
+        if !less_than(&limbs, &M::MODULUS) {
+            return None;
+        }
+        Some(Self::from_montgomery(M::reduce(mul_wide(&limbs, &M::R2))))
+    }
+
+    /// Little-endian limbs of the canonical (non Montgomery) value.
+    pub fn to_limbs(&self) -> [u64; 4] {
+        M::reduce(widen_4(&self.limbs))
+    }
+
+    /// Builds an element from its 32 byte big-endian encoding.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0_u64; 4];
+        for (i, chunk) in bytes.rchunks(8).enumerate() {
+            limbs[i] = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// 32 byte big-endian encoding of the element.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        limbs_to_bytes(&self.to_limbs())
+    }
+
+    pub fn zero() -> Self {
+        Self::from_montgomery([0; 4])
+    }
+
+    pub fn one() -> Self {
+        Self::from(1_u64)
+    }
+
+    /// Raises the element to `exponent`, given as little-endian limbs, by
+    /// square-and-multiply. It branches on the bits of `exponent`, which
+    /// must be public.
+    pub fn pow(&self, exponent: &[u64; 4]) -> Self {
+        let mut result = Self::one();
+        for i in (0..256).rev() {
+            result = result * result;
+            if (exponent[i / 64] >> (i % 64)) & 1 == 1 {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    /// Swaps `a` and `b` when `swap` is 1 and leaves them when it is 0,
+    /// doing the same operations either way.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, swap: u64) {
+        let mask = 0_u64.wrapping_sub(swap);
+        for i in 0..4 {
+            let difference = (a.limbs[i] ^ b.limbs[i]) & mask;
+            a.limbs[i] ^= difference;
+            b.limbs[i] ^= difference;
+        }
+    }
+}
+
+fn limbs_to_bytes(limbs: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0_u8; 32];
+    for (i, limb) in limbs.iter().enumerate() {
+        bytes[24 - 8 * i..32 - 8 * i].copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+fn widen_4(limbs: &[u64; 4]) -> [u64; 8] {
+    [limbs[0], limbs[1], limbs[2], limbs[3], 0, 0, 0, 0]
+}
+
+impl<M: Modulus> From<u64> for MontgomeryFieldElement<M> {
+    fn from(value: u64) -> Self {
+        Self::from_limbs([value, 0, 0, 0]).unwrap()
+    }
+}
+
+impl<M: Modulus> From<i64> for MontgomeryFieldElement<M> {
+    fn from(value: i64) -> Self {
+        let magnitude = Self::from(value.unsigned_abs());
+        if value < 0 {
+            Self::zero() - magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl<M: Modulus> Add for MontgomeryFieldElement<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, carry) = add_limbs(&self.limbs, &rhs.limbs);
+        let (reduced, borrow) = sub_limbs(&sum, &M::MODULUS);
+        Self::from_montgomery(select(mask(carry | !borrow), &reduced, &sum))
+    }
+}
+
+impl<M: Modulus> Sub for MontgomeryFieldElement<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (difference, borrow) = sub_limbs(&self.limbs, &rhs.limbs);
+        let correction = select(mask(borrow), &M::MODULUS, &[0; 4]);
+        Self::from_montgomery(add_limbs(&difference, &correction).0)
+    }
+}
+
+impl<M: Modulus> Mul for MontgomeryFieldElement<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_montgomery(M::reduce(mul_wide(&self.limbs, &rhs.limbs)))
+    }
+}
+
+impl<M: Modulus> Div for MontgomeryFieldElement<M> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match self.checked_div(&rhs) {
+            Ok(quotient) => quotient,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl<M: Modulus> Field for MontgomeryFieldElement<M> {
+    fn zero(&self) -> Self {
+        Self::from_montgomery([0; 4])
+    }
+
+    fn one(&self) -> Self {
+        Self::from(1_u64)
+    }
+
+    fn modulus(&self) -> BigUint {
+        BigUint::from_bytes_be(&limbs_to_bytes(&M::MODULUS))
+    }
+
+    fn element_from_bytes(&self, bytes: &[u8]) -> Option<Self> {
+        let value = BigUint::from_bytes_be(bytes);
+        if value >= self.modulus() {
+            return None;
+        }
+        let value = value.to_bytes_be();
+        let mut padded = [0_u8; 32];
+        padded[32 - value.len()..].copy_from_slice(&value);
+        Self::from_bytes(&padded)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        limbs_to_bytes(&self.to_limbs()).to_vec()
+    }
+
+    /// Fermat inversion on limbs, so every non-zero element takes the same
+    /// sequence of multiplications.
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        // a^-1 == a.pow(p - 2)
+        let exponent = sub_limbs(&M::MODULUS, &[2, 0, 0, 0]).0;
+        Some(self.pow(&exponent))
+    }
+}
+
+impl<M: Modulus> fmt::Display for MontgomeryFieldElement<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.to_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+    use crate::field_element::FieldElement;
+    use crate::point::Point;
+    use crate::secp256k1::{G, N, P};
+    use num_bigint::{BigInt, Sign};
+    use proptest::prelude::*;
+
+    fn to_bigint<M: Modulus>(element: &MontgomeryFieldElement<M>) -> BigInt {
+        BigInt::from_bytes_be(Sign::Plus, &element.to_bytes())
+    }
+
+    fn to_field_element(bytes: &[u8; 32], prime: &BigInt) -> FieldElement {
+        FieldElement::new(
+            BigInt::from_bytes_be(Sign::Plus, bytes) % prime,
+            prime.clone(),
+        )
+    }
+
+    fn from_field_element<M: Modulus>(element: &FieldElement) -> MontgomeryFieldElement<M> {
+        let (_, bytes) = element.num().to_bytes_be();
+        let mut padded = [0_u8; 32];
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        MontgomeryFieldElement::from_bytes(&padded).unwrap()
+    }
+
+    fn check_against_bigint<M: Modulus>(a: &[u8; 32], b: &[u8; 32], prime: &BigInt) {
+        let big_a = to_field_element(a, prime);
+        let big_b = to_field_element(b, prime);
+        let a = from_field_element::<M>(&big_a);
+        let b = from_field_element::<M>(&big_b);
+
+        assert_eq!(to_bigint(&a), *big_a.num());
+        assert_eq!(to_bigint(&(a + b)), *(big_a.clone() + big_b.clone()).num());
+        assert_eq!(to_bigint(&(a - b)), *(big_a.clone() - big_b.clone()).num());
+        assert_eq!(to_bigint(&(a * b)), *(big_a.clone() * big_b.clone()).num());
+        if big_b.num() != &BigInt::from(0) {
+            assert_eq!(to_bigint(&(a / b)), *(big_a.clone() / big_b.clone()).num());
+        }
+        let exponent = from_field_element::<M>(&big_b).to_limbs();
+        assert_eq!(
+            to_bigint(&a.pow(&exponent)),
+            big_a.num().modpow(big_b.num(), prime)
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let element = S256MontgomeryField::from_bytes(&bytes).unwrap();
+
+        assert_eq!(element.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn values_not_below_the_modulus_are_rejected() {
+        assert!(S256MontgomeryField::from_limbs(Secp256k1Modulus::MODULUS).is_none());
+        assert!(S256MontgomeryField::from_bytes(&[0xff; 32]).is_none());
+    }
+
+    #[test]
+    fn conditional_swap_only_swaps_when_asked() {
+        let a = S256MontgomeryField::from(3_u64);
+        let b = S256MontgomeryField::from(5_u64);
+        let (mut x, mut y) = (a, b);
+
+        S256MontgomeryField::conditional_swap(&mut x, &mut y, 0);
+        assert_eq!((x, y), (a, b));
+        S256MontgomeryField::conditional_swap(&mut x, &mut y, 1);
+        assert_eq!((x, y), (b, a));
+    }
+
+    #[test]
+    fn negative_integers_wrap_around_the_modulus() {
+        assert_eq!(
+            to_bigint(&S256MontgomeryField::from(-1_i64)),
+            &*P - BigInt::from(1)
+        );
+    }
+
+    #[test]
+    fn extreme_values_match_bigint() {
+        let p_minus_one = sub_limbs(&Secp256k1Modulus::MODULUS, &[1, 0, 0, 0]).0;
+        let mut max = [0_u8; 32];
+        max.copy_from_slice(
+            &S256MontgomeryField::from_limbs(p_minus_one)
+                .unwrap()
+                .to_bytes(),
+        );
+        let zero = [0_u8; 32];
+
+        check_against_bigint::<Secp256k1Modulus>(&max, &max, &P);
+        check_against_bigint::<Secp256k1Modulus>(&max, &zero, &P);
+        check_against_bigint::<Secp256k1Modulus>(&zero, &max, &P);
+    }
+
+    /// secp256k1 with coordinates in the Montgomery backend.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct MontgomerySecp256k1;
+
+    impl Curve for MontgomerySecp256k1 {
+        type Field = S256MontgomeryField;
+
+        fn a() -> Self::Field {
+            S256MontgomeryField::from(0_i64)
+        }
+
+        fn b() -> Self::Field {
+            S256MontgomeryField::from(7_i64)
+        }
+
+        fn generator() -> Point<Self> {
+            let (x, y) = match &*G {
+                Point::Point(x, y) => (x.to_bytes(), y.to_bytes()),
+                Point::Infinity => unreachable!(),
+            };
+            Point::new_point(
+                S256MontgomeryField::from_bytes(&x).unwrap(),
+                S256MontgomeryField::from_bytes(&y).unwrap(),
+            )
+            .unwrap()
+        }
+
+        fn order() -> BigInt {
+            N.clone()
+        }
+
+        fn cofactor() -> BigInt {
+            BigInt::from(1)
+        }
+    }
+
+    #[test]
+    fn generator_multiples_match_the_bigint_backend() {
+        let generator = MontgomerySecp256k1::generator();
+        let k = BigInt::from(0xdeadbeef_u32) << 200;
+        let expected = G.scalar_mul(&k);
+        let result = generator.scalar_mul(&k);
+
+        assert_eq!(
+            result.x().unwrap().to_bytes(),
+            expected.x().unwrap().to_bytes()
+        );
+        assert_eq!(
+            result.y().unwrap().to_bytes(),
+            expected.y().unwrap().to_bytes()
+        );
+        assert_eq!(generator.scalar_mul(&N), Point::new_infinity());
+    }
+
+    proptest! {
+        #[test]
+        fn secp256k1_field_matches_bigint(a in any::<[u8; 32]>(), b in any::<[u8; 32]>()) {
+            check_against_bigint::<Secp256k1Modulus>(&a, &b, &P);
+        }
+
+        #[test]
+        fn secp256k1_order_matches_bigint(a in any::<[u8; 32]>(), b in any::<[u8; 32]>()) {
+            check_against_bigint::<Secp256k1Order>(&a, &b, &N);
+        }
+
+        #[test]
+        fn specialized_reduction_matches_generic_reduction(
+            a in any::<[u8; 32]>(),
+            b in any::<[u8; 32]>(),
+        ) {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            struct GenericSecp256k1;
+            impl Modulus for GenericSecp256k1 {
+                const MODULUS: [u64; 4] = Secp256k1Modulus::MODULUS;
+                const R2: [u64; 4] = Secp256k1Modulus::R2;
+                const INV: u64 = Secp256k1Modulus::INV;
+            }
+            let a = to_field_element(&a, &P);
+            let b = to_field_element(&b, &P);
+            let wide = mul_wide(
+                &from_field_element::<Secp256k1Modulus>(&a).limbs,
+                &from_field_element::<Secp256k1Modulus>(&b).limbs,
+            );
+            prop_assert_eq!(Secp256k1Modulus::reduce(wide), GenericSecp256k1::reduce(wide));
+        }
+    }
+}
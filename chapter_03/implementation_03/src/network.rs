@@ -0,0 +1,46 @@
+/// Bitcoin network an address or key belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+    Signet,
+    Regtest,
+}
+
+impl Network {
+    /// Version byte prepended to the hash160 of P2PKH addresses.
+    pub fn p2pkh_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            Network::Testnet | Network::Signet | Network::Regtest => 0x6f,
+        }
+    }
+
+    /// Network of a P2PKH version byte. Signet and regtest share the testnet
+    /// version byte, so their addresses are reported as `Testnet`.
+    pub fn from_p2pkh_version(version: u8) -> Option<Self> {
+        match version {
+            0x00 => Some(Network::Mainnet),
+            0x6f => Some(Network::Testnet),
+            _ => None,
+        }
+    }
+
+    /// Prefix byte of private keys in wallet import format.
+    pub fn wif_prefix(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            Network::Testnet | Network::Signet | Network::Regtest => 0xef,
+        }
+    }
+
+    /// Network of a WIF prefix byte. As with addresses, signet and regtest
+    /// keys are reported as `Testnet`.
+    pub fn from_wif_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            0x80 => Some(Network::Mainnet),
+            0xef => Some(Network::Testnet),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use crate::curve::Curve;
+use crate::errors::CurveError;
+use crate::field::Field;
+use crate::jacobian_point::JacobianPoint;
+use num_bigint::{BigInt, Sign};
+use std::ops::{Add, Mul};
+
+/// A point on the curve `C`, with coordinates in its field.
+#[derive(Clone, Copy, Debug)]
+pub enum Point<C: Curve> {
+    Point(C::Field, C::Field),
+    Infinity,
+}
+
+impl<C: Curve> Point<C> {
+    pub fn new_point(x: C::Field, y: C::Field) -> Result<Self, CurveError> {
+        Self::try_new(x, y)
+    }
+
+    /// Builds the point (x, y), failing if it does not satisfy the curve
+    /// equation.
+    pub fn try_new(x: C::Field, y: C::Field) -> Result<Self, CurveError> {
+        let point = Self::Point(x, y);
+        match point.is_on_curve() {
+            true => Ok(point),
+            false => Err(CurveError::NotOnCurve),
+        }
+    }
+
+    pub fn new_infinity() -> Self {
+        Self::Infinity
+    }
+
+    /// Whether the point satisfies the curve equation. The variants are
+    /// public, so points that did not go through `try_new` may not.
+    pub fn is_on_curve(&self) -> bool {
+        match self {
+            Self::Infinity => true,
+            Self::Point(x, y) => {
+                y.clone() * y.clone()
+                    == x.clone() * x.clone() * x.clone() + C::a() * x.clone() + C::b()
+            }
+        }
+    }
+
+    /// Adds `other` after checking that both operands are on the curve, so
+    /// points built from untrusted coordinates can not yield a bogus sum.
+    pub fn try_add(&self, other: &Self) -> Result<Self, CurveError> {
+        if !self.is_on_curve() || !other.is_on_curve() {
+            return Err(CurveError::NotOnCurve);
+        }
+        Ok(self.clone() + other.clone())
+    }
+
+    pub fn x(&self) -> Option<&C::Field> {
+        match self {
+            Self::Point(x, _) => Some(x),
+            Self::Infinity => None,
+        }
+    }
+
+    pub fn y(&self) -> Option<&C::Field> {
+        match self {
+            Self::Point(_, y) => Some(y),
+            Self::Infinity => None,
+        }
+    }
+
+    pub fn negate(&self) -> Self {
+        match self {
+            Self::Infinity => Self::new_infinity(),
+            Self::Point(x, y) => Self::Point(x.clone(), y.zero() - y.clone()),
+        }
+    }
+
+    /// Computes k·P with the double-and-add method.
+    pub fn scalar_mul(&self, k: &BigInt) -> Self {
+        if k.sign() == Sign::Minus {
+            return self.negate().scalar_mul(&-k);
+        }
+        let mut result = Self::new_infinity();
+        let mut current = self.clone();
+        for i in 0..k.bits() {
+            if k.bit(i) {
+                result = result + current.clone();
+            }
+            current = current.clone() + current;
+        }
+        result
+    }
+
+    /// Computes k·P from the width-4 non-adjacent form of `k`, with the
+    /// arithmetic done in Jacobian coordinates.
+    pub fn mul_wnaf(&self, k: &BigInt) -> Self {
+        JacobianPoint::mul_wnaf(self, k)
+    }
+
+    /// Computes the sum of k·P over all `terms` at once, see
+    /// `JacobianPoint::multi_mul`.
+    pub fn multi_mul(terms: &[(BigInt, Self)]) -> Self {
+        JacobianPoint::multi_mul(terms)
+    }
+}
+
+impl<C: Curve> PartialEq for Point<C> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Infinity, Self::Infinity) => true,
+            (Self::Point(x1, y1), Self::Point(x2, y2)) => x1 == x2 && y1 == y2,
+            _ => false,
+        }
+    }
+}
+
+impl<C: Curve> Add<Point<C>> for Point<C> {
+    type Output = Self;
+    fn add(self, other: Point<C>) -> Self {
+        match (self, other) {
+            (Self::Infinity, other) => other,
+            (point, Self::Infinity) => point,
+            (Self::Point(x1, y1), Self::Point(x2, y2)) if x1 == x2 && y1 != y2 => {
+                Self::new_infinity()
+            }
+            (Self::Point(x1, y1), Self::Point(x2, _)) if x1 == x2 && y1.is_zero() => {
+                Self::new_infinity()
+            }
+            (Self::Point(x1, y1), Self::Point(x2, _)) if x1 == x2 => {
+                let slope = (C::Field::from(3) * x1.clone() * x1.clone() + C::a())
+                    / (C::Field::from(2) * y1.clone());
+                let x3 = slope.clone() * slope.clone() - C::Field::from(2) * x1.clone();
+                let y3 = slope * (x1 - x3.clone()) - y1;
+                Self::Point(x3, y3)
+            }
+            (Self::Point(x1, y1), Self::Point(x2, y2)) => {
+                let slope = (y2 - y1.clone()) / (x2.clone() - x1.clone());
+                let x3 = slope.clone() * slope.clone() - x1.clone() - x2;
+                let y3 = slope * (x1 - x3.clone()) - y1;
+                Self::Point(x3, y3)
+            }
+        }
+    }
+}
+
+impl<C: Curve> Mul<BigInt> for Point<C> {
+    type Output = Self;
+    fn mul(self, k: BigInt) -> Self {
+        self.scalar_mul(&k)
+    }
+}
+
+#[cfg(test)]
+mod point_tests {
+    use super::*;
+    use crate::curve::F223Curve;
+    use crate::finite_field_element::FiniteFieldElement;
+
+    type F223 = FiniteFieldElement<223>;
+    type Point223 = Point<F223Curve>;
+
+    fn point(x: i128, y: i128) -> Result<Point223, CurveError> {
+        Point223::new_point(F223::new(x), F223::new(y))
+    }
+
+    #[test]
+    fn test00_points_on_the_curve_can_be_created() {
+        for (x, y) in [(192, 105), (17, 56), (1, 193)] {
+            assert!(point(x, y).is_ok());
+        }
+    }
+
+    #[test]
+    fn test01_points_off_the_curve_can_not_be_created() {
+        for (x, y) in [(200, 119), (42, 99)] {
+            assert!(point(x, y).is_err());
+        }
+    }
+
+    #[test]
+    fn test02_points_with_same_cords_are_equal() {
+        assert_eq!(point(192, 105).unwrap(), point(192, 105).unwrap());
+        assert_ne!(point(192, 105).unwrap(), point(17, 56).unwrap());
+    }
+
+    #[test]
+    fn test03_sum_infinity_and_another_point_returns_the_point() {
+        let infinity = Point223::new_infinity();
+        let point = point(192, 105).unwrap();
+
+        assert_eq!(point.clone() + infinity.clone(), point);
+        assert_eq!(infinity + point.clone(), point);
+    }
+
+    #[test]
+    fn test04_two_different_points_from_the_same_curve_can_be_added() {
+        let additions = [
+            ((192, 105), (17, 56), (170, 142)),
+            ((47, 71), (117, 141), (60, 139)),
+            ((143, 98), (76, 66), (47, 71)),
+        ];
+        for ((x1, y1), (x2, y2), (x3, y3)) in additions {
+            assert_eq!(
+                point(x1, y1).unwrap() + point(x2, y2).unwrap(),
+                point(x3, y3).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test05_adding_a_point_to_its_inverse_results_infinity() {
+        let point1 = point(192, 105).unwrap();
+        let point2 = point(192, 223 - 105).unwrap();
+
+        assert_eq!(point1 + point2, Point223::new_infinity());
+    }
+
+    #[test]
+    fn test06_a_point_can_be_doubled() {
+        let doublings = [
+            ((192, 105), (49, 71)),
+            ((143, 98), (64, 168)),
+            ((47, 71), (36, 111)),
+        ];
+        for ((x1, y1), (x2, y2)) in doublings {
+            let p = point(x1, y1).unwrap();
+            assert_eq!(p.clone() + p, point(x2, y2).unwrap());
+        }
+    }
+
+    #[test]
+    fn test07_doubling_twice_is_four_times_the_point() {
+        let p = point(47, 71).unwrap();
+        let two_p = p.clone() + p;
+
+        assert_eq!(two_p.clone() + two_p, point(194, 51).unwrap());
+    }
+
+    #[test]
+    fn test08_scalar_multiplication_with_double_and_add() {
+        let multiplications = [
+            (2, (192, 105), Some((49, 71))),
+            (2, (143, 98), Some((64, 168))),
+            (2, (47, 71), Some((36, 111))),
+            (4, (47, 71), Some((194, 51))),
+            (8, (47, 71), Some((116, 55))),
+            (21, (47, 71), None),
+        ];
+        for (k, (x1, y1), expected) in multiplications {
+            let expected = match expected {
+                Some((x2, y2)) => point(x2, y2).unwrap(),
+                None => Point223::new_infinity(),
+            };
+            let p = point(x1, y1).unwrap();
+
+            assert_eq!(p.scalar_mul(&BigInt::from(k)), expected);
+            assert_eq!(p * BigInt::from(k), expected);
+        }
+    }
+
+    #[test]
+    fn test10_multiplying_by_a_negative_scalar_negates_the_result() {
+        let p = point(47, 71).unwrap();
+
+        assert_eq!(
+            p.scalar_mul(&BigInt::from(-4)),
+            point(194, 223 - 51).unwrap()
+        );
+    }
+
+    #[test]
+    fn test12_wnaf_multiplication_matches_double_and_add() {
+        let p = point(47, 71).unwrap();
+        for k in -25..=25 {
+            let k = BigInt::from(k);
+            assert_eq!(p.mul_wnaf(&k), p.scalar_mul(&k));
+        }
+    }
+
+    #[test]
+    fn test13_multi_mul_matches_the_sum_of_multiplications() {
+        let p = point(47, 71).unwrap();
+        let q = point(17, 56).unwrap();
+        for (a, b) in [(0, 0), (1, 2), (-3, 5), (7, 7), (13, -21)] {
+            let (a, b) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(
+                Point223::multi_mul(&[(a.clone(), p.clone()), (b.clone(), q.clone())]),
+                p.scalar_mul(&a) + q.scalar_mul(&b)
+            );
+        }
+    }
+
+    #[test]
+    fn test14_fallible_construction_reports_points_off_the_curve() {
+        assert_eq!(
+            Point223::try_new(F223::new(200), F223::new(119)),
+            Err(CurveError::NotOnCurve)
+        );
+        assert_eq!(
+            Point223::try_new(F223::new(192), F223::new(105)),
+            Ok(point(192, 105).unwrap())
+        );
+    }
+
+    #[test]
+    fn test15_fallible_addition_rejects_operands_off_the_curve() {
+        let p = point(192, 105).unwrap();
+        let off_curve = Point223::Point(F223::new(42), F223::new(99));
+
+        assert_eq!(p.try_add(&off_curve), Err(CurveError::NotOnCurve));
+        assert_eq!(off_curve.try_add(&p), Err(CurveError::NotOnCurve));
+        assert_eq!(
+            p.try_add(&point(17, 56).unwrap()),
+            Ok(point(170, 142).unwrap())
+        );
+        assert_eq!(p.try_add(&Point223::new_infinity()), Ok(p.clone()));
+    }
+}
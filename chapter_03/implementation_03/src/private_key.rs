@@ -0,0 +1,318 @@
+use crate::base58::{decode_check, encode_check};
+use crate::errors::{KeyError, WifError};
+use crate::field::Field;
+use crate::helpers::{big_endian_to_int, int_to_big_endian};
+use crate::montgomery_field::S256Scalar;
+use crate::network::Network;
+use crate::public_key::PublicKey;
+use crate::rfc6979::deterministic_k;
+use crate::secp256k1::{mod_n, scalar_bytes, S256Point, N};
+use crate::signature::Signature;
+use num_bigint::BigInt;
+
+/// A secp256k1 secret along with its public key.
+///
+/// The secret and the nonces only go through `S256Point::mul_generator_ct`
+/// and fixed-width scalar arithmetic.
+#[derive(Debug, Clone)]
+pub struct PrivateKey {
+    secret: BigInt,
+    secret_scalar: S256Scalar,
+    public_key: PublicKey,
+}
+
+impl PrivateKey {
+    pub fn new(secret: BigInt) -> Result<Self, KeyError> {
+        if secret < BigInt::from(1) || secret >= *N {
+            return Err(KeyError::SecretOutOfRange);
+        }
+        let secret_scalar = to_scalar(&secret);
+        let public_key = PublicKey::new(S256Point::mul_generator_ct(&secret))?;
+        Ok(Self {
+            secret,
+            secret_scalar,
+            public_key,
+        })
+    }
+
+    pub fn secret(&self) -> &BigInt {
+        &self.secret
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Wallet import format of the secret, flagging whether its public key
+    /// is meant to be used compressed.
+    pub fn to_wif(&self, compressed: bool, network: Network) -> String {
+        let mut payload = vec![network.wif_prefix()];
+        payload.extend(int_to_big_endian(&self.secret, 32).expect("Secrets are below N"));
+        if compressed {
+            payload.push(0x01);
+        }
+        encode_check(&payload)
+    }
+
+    /// Parses a key in wallet import format, returning it along with its
+    /// compression flag and network.
+    pub fn from_wif(wif: &str) -> Result<(Self, bool, Network), WifError> {
+        let payload = decode_check(wif)?;
+        let compressed = match payload.len() {
+            33 => false,
+            34 if payload[33] == 0x01 => true,
+            34 => return Err(WifError::InvalidCompressionFlag(payload[33])),
+            length => return Err(WifError::InvalidLength(length)),
+        };
+        let network =
+            Network::from_wif_prefix(payload[0]).ok_or(WifError::UnknownPrefix(payload[0]))?;
+        let secret = big_endian_to_int(&payload[1..33]);
+        let private_key = Self::new(secret)?;
+        Ok((private_key, compressed, network))
+    }
+
+    /// Signs the message hash `z` with the RFC 6979 nonce for this key and `z`.
+    ///
+    /// `z` must be a 256-bit hash: other values could share a nonce with a
+    /// different hash, which would reveal the secret.
+    pub fn sign(&self, z: &BigInt) -> Result<Signature, KeyError> {
+        let message_hash = int_to_big_endian(z, 32).map_err(|_| KeyError::MessageHashOutOfRange)?;
+        let k = deterministic_k(&self.secret, &message_hash, &N);
+        self.sign_with_nonce(z, &k)
+    }
+
+    /// Signs the message hash `z` with the nonce `k`, normalizing s to the
+    /// lower half of the group order.
+    ///
+    /// `k` must be in the range 1 to N - 1, and is also refused in the
+    /// negligible case where it gives a zero r or s.
+    pub fn sign_with_nonce(&self, z: &BigInt, k: &BigInt) -> Result<Signature, KeyError> {
+        if k < &BigInt::from(1) || k >= &*N {
+            return Err(KeyError::NonceOutOfRange);
+        }
+        let r = match S256Point::mul_generator_ct(k).x() {
+            Some(x) => mod_n(x.num()),
+            None => return Err(KeyError::NonceOutOfRange),
+        };
+        let k_inverse = to_scalar(k).inverse().ok_or(KeyError::NonceOutOfRange)?;
+        let s = (to_scalar(z) + to_scalar(&r) * self.secret_scalar) * k_inverse;
+        let s = big_endian_to_int(&s.to_bytes());
+        let s = if s > &*N / 2 { &*N - s } else { s };
+        Signature::new(r, s).map_err(|_| KeyError::NonceOutOfRange)
+    }
+}
+
+fn to_scalar(value: &BigInt) -> S256Scalar {
+    S256Scalar::from_bytes(&scalar_bytes(value)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secp256k1::from_hex;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn secrets_out_of_range_are_rejected() {
+        assert_eq!(
+            PrivateKey::new(BigInt::from(0)).err(),
+            Some(KeyError::SecretOutOfRange)
+        );
+        assert_eq!(
+            PrivateKey::new(N.clone()).err(),
+            Some(KeyError::SecretOutOfRange)
+        );
+        assert!(PrivateKey::new(&*N - 1).is_ok());
+    }
+
+    #[test]
+    fn sign_with_a_known_nonce() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+        let signature = private_key
+            .sign_with_nonce(&z, &BigInt::from(1234567890))
+            .unwrap();
+
+        assert_eq!(
+            signature,
+            Signature::new(
+                from_hex("2b698a0f0a4041b77e63488ad48c23e8e8838dd1fb7520408b121697b782ef22"),
+                from_hex("1dbc63bfef4416705e602a7b564161167076d8b20990a0f26f316cff2cb0bc1a"),
+            )
+            .unwrap()
+        );
+        assert!(private_key.public_key().verify(&z, &signature));
+    }
+
+    #[test]
+    fn nonces_out_of_range_are_rejected() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let z = BigInt::from(0xdeadbeef_u32);
+
+        for k in [BigInt::from(0), N.clone(), &*N + 1, BigInt::from(-1)] {
+            assert_eq!(
+                private_key.sign_with_nonce(&z, &k),
+                Err(KeyError::NonceOutOfRange)
+            );
+        }
+        assert!(private_key.sign_with_nonce(&z, &(&*N - 1)).is_ok());
+    }
+
+    #[test]
+    fn high_s_values_are_normalized() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+        let signature = private_key
+            .sign_with_nonce(&z, &BigInt::from(1234567891))
+            .unwrap();
+
+        assert_eq!(
+            *signature.s(),
+            from_hex("5f932eb6e24834bfa06442d406b8af8a01d0e95bf76b14d1334f8e0b368e5b9f")
+        );
+        assert!(private_key.public_key().verify(&z, &signature));
+    }
+
+    #[test]
+    fn signatures_verify_against_the_matching_public_key() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let other_key = PrivateKey::new(BigInt::from(54321)).unwrap();
+        let z = BigInt::from(0xdeadbeef_u32);
+        let signature = private_key.sign(&z).unwrap();
+
+        assert!(signature.s() <= &(&*N / 2));
+        assert!(private_key.public_key().verify(&z, &signature));
+        assert!(!other_key.public_key().verify(&z, &signature));
+    }
+
+    #[test]
+    fn signing_is_deterministic() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let z = BigInt::from(0xdeadbeef_u32);
+
+        assert_eq!(private_key.sign(&z), private_key.sign(&z));
+    }
+
+    #[test]
+    fn message_hashes_outside_256_bits_are_rejected() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let z = from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48");
+
+        assert!(private_key.sign(&z).is_ok());
+        assert_eq!(
+            private_key.sign(&(&z + (BigInt::from(1) << 256))),
+            Err(KeyError::MessageHashOutOfRange)
+        );
+        assert_eq!(private_key.sign(&-&z), Err(KeyError::MessageHashOutOfRange));
+    }
+
+    #[test]
+    fn hashes_equal_modulo_n_share_nonce_and_signature() {
+        let private_key = PrivateKey::new(BigInt::from(12345)).unwrap();
+        let z = &*N + 7;
+
+        assert_eq!(private_key.sign(&z), private_key.sign(&BigInt::from(7)));
+    }
+
+    #[test]
+    fn deterministic_signature_vectors() {
+        let vectors = [
+            (
+                BigInt::from(1),
+                "Satoshi Nakamoto",
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+                "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ),
+            (
+                from_hex("f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181"),
+                "Alan Turing",
+                "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c",
+                "58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea",
+            ),
+        ];
+        for (secret, message, r, s) in vectors {
+            let private_key = PrivateKey::new(secret).unwrap();
+            let z = big_endian_to_int(&Sha256::digest(message.as_bytes()));
+
+            assert_eq!(
+                private_key.sign(&z),
+                Ok(Signature::new(from_hex(r), from_hex(s)).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn wif_vectors_from_the_book() {
+        let vectors = [
+            (
+                (BigInt::from(1) << 256) - (BigInt::from(1) << 199),
+                true,
+                Network::Mainnet,
+                "L5oLkpV3aqBJ4BgssVAsax1iRa77G5CVYnv9adQ6Z87te7TyUdSC",
+            ),
+            (
+                (BigInt::from(1) << 256) - (BigInt::from(1) << 201),
+                false,
+                Network::Testnet,
+                "93XfLeifX7Jx7n7ELGMAf1SUR6f9kgQs8Xke8WStMwUtrDucMzn",
+            ),
+            (
+                from_hex("0dba685b4511dbd3d368e5c4358a1277de9486447af7b3604a69b8d9d8b7889d"),
+                false,
+                Network::Mainnet,
+                "5HvLFPDVgFZRK9cd4C5jcWki5Skz6fmKqi1GQJf5ZoMofid2Dty",
+            ),
+            (
+                from_hex("1cca23de92fd1862fb5b76e5f4f50eb082165e5191e116c18ed1a6b24be6a53f"),
+                true,
+                Network::Testnet,
+                "cNYfWuhDpbNM1JWc3c6JTrtrFVxU4AGhUKgw5f93NP2QaBqmxKkg",
+            ),
+        ];
+        for (secret, compressed, network, wif) in vectors {
+            let private_key = PrivateKey::new(secret.clone()).unwrap();
+            assert_eq!(private_key.to_wif(compressed, network), wif);
+
+            let (parsed, parsed_compressed, parsed_network) = PrivateKey::from_wif(wif).unwrap();
+            assert_eq!(parsed.secret(), &secret);
+            assert_eq!(parsed_compressed, compressed);
+            assert_eq!(parsed_network, network);
+        }
+    }
+
+    #[test]
+    fn invalid_wif_keys_are_rejected() {
+        let wif_payload = |prefix: u8, secret: &BigInt, suffix: &[u8]| {
+            let mut payload = vec![prefix];
+            payload.extend(int_to_big_endian(secret, 32).unwrap());
+            payload.extend_from_slice(suffix);
+            encode_check(&payload)
+        };
+        let one = BigInt::from(1);
+
+        assert_eq!(
+            PrivateKey::from_wif(&wif_payload(0x80, &BigInt::from(0), &[0x01])).err(),
+            Some(WifError::Key(KeyError::SecretOutOfRange))
+        );
+        assert_eq!(
+            PrivateKey::from_wif(&wif_payload(0x80, &N, &[0x01])).err(),
+            Some(WifError::Key(KeyError::SecretOutOfRange))
+        );
+        assert_eq!(
+            PrivateKey::from_wif(&wif_payload(0x81, &one, &[0x01])).err(),
+            Some(WifError::UnknownPrefix(0x81))
+        );
+        assert_eq!(
+            PrivateKey::from_wif(&wif_payload(0x80, &one, &[0x02])).err(),
+            Some(WifError::InvalidCompressionFlag(0x02))
+        );
+        assert_eq!(
+            PrivateKey::from_wif(&wif_payload(0x80, &one, &[0x01, 0x01])).err(),
+            Some(WifError::InvalidLength(35))
+        );
+        assert!(matches!(
+            PrivateKey::from_wif("L5oLkpV3aqBJ4BgssVAsax1iRa77G5CVYnv9adQ6Z87te7TyUdSD"),
+            Err(WifError::Base58(_))
+        ));
+    }
+}
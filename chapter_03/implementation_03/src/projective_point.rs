@@ -0,0 +1,192 @@
+use crate::field::Field;
+use crate::helpers::big_endian_to_int;
+use crate::montgomery_field::S256MontgomeryField;
+use crate::secp256k1::{S256Field, S256Point};
+
+/// secp256k1 point in homogeneous projective coordinates, (X : Y : Z) being
+/// the affine point (X/Z, Y/Z), over the fixed-width Montgomery field.
+///
+/// Sums use the complete formulas of Renes, Costello and Batina (2016,
+/// algorithm 7 for a = 0), which also hold for doublings, inverse points and
+/// the point at infinity (0 : 1 : 0), so every addition runs the same field
+/// operations whatever its operands. This is what secret scalars go through.
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectivePoint {
+    x: S256MontgomeryField,
+    y: S256MontgomeryField,
+    z: S256MontgomeryField,
+}
+
+impl ProjectivePoint {
+    pub fn new_infinity() -> Self {
+        Self {
+            x: S256MontgomeryField::zero(),
+            y: S256MontgomeryField::one(),
+            z: S256MontgomeryField::zero(),
+        }
+    }
+
+    pub fn from_affine(point: &S256Point) -> Self {
+        match point {
+            S256Point::Infinity => Self::new_infinity(),
+            S256Point::Point(x, y) => Self {
+                x: S256MontgomeryField::from_bytes(&x.to_bytes()).unwrap(),
+                y: S256MontgomeryField::from_bytes(&y.to_bytes()).unwrap(),
+                z: S256MontgomeryField::one(),
+            },
+        }
+    }
+
+    /// Affine form of the point, with one inversion.
+    pub fn to_affine(&self) -> S256Point {
+        let Some(z_inverse) = self.z.inverse() else {
+            return S256Point::new_infinity();
+        };
+        let coordinate = |value: S256MontgomeryField| {
+            S256Field::new(big_endian_to_int(&(value * z_inverse).to_bytes()))
+        };
+        S256Point::Point(coordinate(self.x), coordinate(self.y))
+    }
+
+    /// Complete addition, valid for any two points including equal ones.
+    pub fn add_complete(&self, other: &Self) -> Self {
+        let b3 = S256MontgomeryField::from(21_u64);
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * (x2 + y2) - (t0 + t1);
+        let t4 = (y1 + z1) * (y2 + z2) - (t1 + t2);
+        let y3 = (x1 + z1) * (x2 + z2) - (t0 + t2);
+        let t0 = t0 + t0 + t0;
+        let t2 = b3 * t2;
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = b3 * y3;
+        Self {
+            x: t3 * t1 - t4 * y3,
+            y: y3 * t0 + t1 * z3,
+            z: z3 * t4 + t0 * t3,
+        }
+    }
+
+    /// Swaps `a` and `b` when `swap` is 1 and leaves them when it is 0,
+    /// doing the same operations either way.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, swap: u64) {
+        S256MontgomeryField::conditional_swap(&mut a.x, &mut b.x, swap);
+        S256MontgomeryField::conditional_swap(&mut a.y, &mut b.y, swap);
+        S256MontgomeryField::conditional_swap(&mut a.z, &mut b.z, swap);
+    }
+
+    /// `table[index]`, found by reading every entry and keeping the right one
+    /// with a mask, so the memory accessed does not depend on `index`.
+    pub fn select(table: &[Self], index: u64) -> Self {
+        let mut selected = Self::new_infinity();
+        for (i, entry) in table.iter().enumerate() {
+            let is_index = ((i as u64 ^ index).wrapping_sub(1)) >> 63;
+            let mut candidate = *entry;
+            Self::conditional_swap(&mut selected, &mut candidate, is_index);
+        }
+        selected
+    }
+
+    /// Computes k·P for the 256-bit big-endian scalar `k` with a Montgomery
+    /// ladder.
+    ///
+    /// Every one of the 256 steps swaps the registers with a mask, adds them
+    /// and doubles one of them with `add_complete`, so neither the sequence
+    /// of operations nor the memory accessed depends on the bits of `k`.
+    pub fn ladder(&self, k: &[u8; 32]) -> Self {
+        let mut r0 = Self::new_infinity();
+        let mut r1 = *self;
+        let mut swapped = 0_u64;
+        for i in (0..256).rev() {
+            let bit = ((k[31 - i / 8] >> (i % 8)) & 1) as u64;
+            Self::conditional_swap(&mut r0, &mut r1, swapped ^ bit);
+            swapped = bit;
+            r1 = r0.add_complete(&r1);
+            r0 = r0.add_complete(&r0);
+        }
+        Self::conditional_swap(&mut r0, &mut r1, swapped);
+        r0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::int_to_big_endian;
+    use crate::secp256k1::{G, N};
+    use num_bigint::BigInt;
+    use proptest::prelude::*;
+
+    fn scalar(k: &BigInt) -> [u8; 32] {
+        int_to_big_endian(k, 32).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn complete_addition_handles_every_special_case() {
+        let g = ProjectivePoint::from_affine(&G);
+        let infinity = ProjectivePoint::new_infinity();
+        let minus_g = ProjectivePoint::from_affine(&G.negate());
+
+        assert_eq!(g.add_complete(&infinity).to_affine(), *G);
+        assert_eq!(infinity.add_complete(&g).to_affine(), *G);
+        assert_eq!(
+            infinity.add_complete(&infinity).to_affine(),
+            S256Point::new_infinity()
+        );
+        assert_eq!(
+            g.add_complete(&minus_g).to_affine(),
+            S256Point::new_infinity()
+        );
+        assert_eq!(
+            g.add_complete(&g).to_affine(),
+            G.scalar_mul(&BigInt::from(2))
+        );
+    }
+
+    #[test]
+    fn ladder_edge_scalars() {
+        let g = ProjectivePoint::from_affine(&G);
+
+        assert_eq!(
+            g.ladder(&scalar(&BigInt::from(0))).to_affine(),
+            S256Point::new_infinity()
+        );
+        assert_eq!(g.ladder(&scalar(&BigInt::from(1))).to_affine(), *G);
+        assert_eq!(g.ladder(&scalar(&(&*N - 1))).to_affine(), G.negate());
+        assert_eq!(g.ladder(&scalar(&N)).to_affine(), S256Point::new_infinity());
+        assert_eq!(
+            ProjectivePoint::new_infinity()
+                .ladder(&scalar(&BigInt::from(7)))
+                .to_affine(),
+            S256Point::new_infinity()
+        );
+    }
+
+    #[test]
+    fn select_reads_the_requested_entry() {
+        let table: Vec<_> = (0..16)
+            .map(|i| ProjectivePoint::from_affine(&G.scalar_mul(&BigInt::from(i))))
+            .collect();
+        for (i, entry) in table.iter().enumerate() {
+            assert_eq!(
+                ProjectivePoint::select(&table, i as u64).to_affine(),
+                entry.to_affine()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ladder_matches_wnaf(k in any::<[u8; 32]>(), base in 1_u64..1000) {
+            let point = G.scalar_mul(&BigInt::from(base));
+            let expected = point.mul_wnaf(&big_endian_to_int(&k));
+
+            prop_assert_eq!(ProjectivePoint::from_affine(&point).ladder(&k).to_affine(), expected);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::address::p2pkh_address;
+use crate::errors::KeyError;
+use crate::helpers::hash160;
+use crate::network::Network;
+use crate::secp256k1::{inverse_mod_n, mod_n, S256Point, G};
+use crate::signature::Signature;
+use num_bigint::BigInt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicKey {
+    point: S256Point,
+}
+
+impl PublicKey {
+    /// Wraps `point`, refusing the point at infinity: every signature with
+    /// r and s in range verifies against it.
+    pub fn new(point: S256Point) -> Result<Self, KeyError> {
+        match point {
+            S256Point::Infinity => Err(KeyError::PublicKeyAtInfinity),
+            point => Ok(Self { point }),
+        }
+    }
+
+    pub fn point(&self) -> &S256Point {
+        &self.point
+    }
+
+    /// hash160 of the SEC encoding of the key.
+    pub fn hash160(&self, compressed: bool) -> [u8; 20] {
+        hash160(&self.point.to_sec(compressed))
+    }
+
+    /// P2PKH address of the key on `network`.
+    pub fn address(&self, compressed: bool, network: Network) -> String {
+        p2pkh_address(&self.hash160(compressed), network)
+    }
+
+    /// Checks that `signature` signs the message hash `z` with this key.
+    pub fn verify(&self, z: &BigInt, signature: &Signature) -> bool {
+        let Some(terms) = self.verification_terms(z, signature) else {
+            return false;
+        };
+        match S256Point::multi_mul(&terms).x() {
+            Some(x) => mod_n(x.num()) == *signature.r(),
+            None => false,
+        }
+    }
+
+    /// The terms u·G and v·P whose sum has an x-coordinate equal to r modulo
+    /// N when `signature` is valid, or `None` when the key is the point at
+    /// infinity. `Signature` already keeps r and s in the range 1 to N - 1.
+    pub(crate) fn verification_terms(
+        &self,
+        z: &BigInt,
+        signature: &Signature,
+    ) -> Option<Vec<(BigInt, S256Point)>> {
+        if self.point == S256Point::Infinity {
+            return None;
+        }
+        let s_inv = inverse_mod_n(signature.s());
+        let u = mod_n(&(z * &s_inv));
+        let v = mod_n(&(signature.r() * &s_inv));
+        Some(vec![(u, G.clone()), (v, self.point.clone())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::parse_p2pkh_address;
+    use crate::private_key::PrivateKey;
+    use crate::secp256k1::{from_hex, S256Field};
+
+    fn book_public_key() -> PublicKey {
+        PublicKey::new(
+            S256Point::new_point(
+                S256Field::new(from_hex(
+                    "887387e452b8eacc4acfde10d9aaf7f6d9a0f975aabb10d006e4da568744d06c",
+                )),
+                S256Field::new(from_hex(
+                    "61de6d95231cd89026e286df3b6ae4a894a3378e393e93a0f45b666329a0ae34",
+                )),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn verify_signatures_from_the_book() {
+        let vectors = [
+            (
+                "ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f60",
+                "ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395",
+                "68342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4",
+            ),
+            (
+                "7c076ff316692a3d7eb3c3bb0f8b1488cf72e1afcd929e29307032997a838a3d",
+                "eff69ef2b1bd93a66ed5219add4fb51e11a840f404876325a1e8ffe0529a2c",
+                "c7207fee197d27c618aea621406f6bf5ef6fca38681d82b2f06fddbdce6feab6",
+            ),
+        ];
+        let public_key = book_public_key();
+        for (z, r, s) in vectors {
+            let signature = Signature::new(from_hex(r), from_hex(s)).unwrap();
+            assert!(public_key.verify(&from_hex(z), &signature));
+        }
+    }
+
+    #[test]
+    fn reject_a_signature_for_another_message() {
+        let z = from_hex("ec208baa0fc1c19f708a9ca96fdeff3ac3f230bb4a7ba4aede4942ad003c0f61");
+        let signature = Signature::new(
+            from_hex("ac8d1c87e51d0d441be8b3dd5b05c8795b48875dffe00b7ffcfac23010d3a395"),
+            from_hex("68342ceff8935ededd102dd876ffd6ba72d6a427a3edb13d26eb0781cb423c4"),
+        )
+        .unwrap();
+        assert!(!book_public_key().verify(&z, &signature));
+    }
+
+    #[test]
+    fn the_point_at_infinity_is_not_a_public_key() {
+        assert_eq!(
+            PublicKey::new(S256Point::new_infinity()),
+            Err(KeyError::PublicKeyAtInfinity)
+        );
+    }
+
+    #[test]
+    fn reject_a_forged_signature_for_the_point_at_infinity() {
+        // u·G + v·O = k·G when s = z / k, whatever the secret
+        let infinity_key = PublicKey {
+            point: S256Point::new_infinity(),
+        };
+        let z = BigInt::from(0xdeadbeef_u32);
+        let k = BigInt::from(1234567890);
+        let r = mod_n(G.scalar_mul(&k).x().unwrap().num());
+        let s = mod_n(&(&z * inverse_mod_n(&k)));
+        let forged = Signature::new(r, s).unwrap();
+
+        assert!(!infinity_key.verify(&z, &forged));
+        assert!(!Signature::verify_batch(&[(infinity_key, z, forged)]));
+    }
+
+    #[test]
+    fn p2pkh_addresses_from_the_book() {
+        let vectors = [
+            (
+                BigInt::from(5002),
+                false,
+                Network::Testnet,
+                "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA",
+            ),
+            (
+                BigInt::from(2020).pow(5),
+                true,
+                Network::Testnet,
+                "mopVkxp8UhXqRYbCYJsbeE1h1fiF64jcoH",
+            ),
+            (
+                from_hex("12345deadbeef"),
+                true,
+                Network::Mainnet,
+                "1F1Pn2y6pDb68E5nYJJeba4TLg2U7B6KF1",
+            ),
+        ];
+        for (secret, compressed, network, address) in vectors {
+            let public_key = PrivateKey::new(secret).unwrap().public_key().clone();
+
+            assert_eq!(public_key.address(compressed, network), address);
+            assert_eq!(
+                parse_p2pkh_address(address).unwrap(),
+                (network, public_key.hash160(compressed))
+            );
+        }
+    }
+
+    #[test]
+    fn signet_and_regtest_use_the_testnet_version_byte() {
+        let public_key = PrivateKey::new(BigInt::from(5002))
+            .unwrap()
+            .public_key()
+            .clone();
+
+        for network in [Network::Signet, Network::Regtest] {
+            assert_eq!(
+                public_key.address(false, network),
+                "mmTPbXQFxboEtNRkwfh6K51jvdtHLxGeMA"
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::helpers::int_to_big_endian;
+use hmac::{Hmac, Mac};
+use num_bigint::{BigInt, Sign};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Derives the ECDSA nonce for `secret` and the bytes of the message hash
+/// as described in RFC 6979, section 3.2, using HMAC-SHA256 and a group of
+/// order `order`.
+///
+/// The signature must use the same hash, read as `bits_to_int` does, or
+/// signing different hashes can reuse a nonce.
+///
+/// # Panics
+///
+/// If `secret` is negative or wider than `order`.
+pub fn deterministic_k(secret: &BigInt, message_hash: &[u8], order: &BigInt) -> BigInt {
+    let qlen = order.bits();
+    let rlen = qlen.div_ceil(8) as usize;
+    let secret_octets = int_to_octets(secret, rlen);
+    let hash_octets = int_to_octets(&(bits_to_int(message_hash, qlen) % order), rlen);
+
+    let mut v = [1_u8; 32].to_vec();
+    let mut k = [0_u8; 32].to_vec();
+    k = hmac(&k, &[&v, &[0x00], &secret_octets, &hash_octets]);
+    v = hmac(&k, &[&v]);
+    k = hmac(&k, &[&v, &[0x01], &secret_octets, &hash_octets]);
+    v = hmac(&k, &[&v]);
+
+    loop {
+        let mut t = Vec::new();
+        while (t.len() as u64) * 8 < qlen {
+            v = hmac(&k, &[&v]);
+            t.extend_from_slice(&v);
+        }
+        let candidate = bits_to_int(&t, qlen);
+        if candidate >= BigInt::from(1) && candidate < *order {
+            return candidate;
+        }
+        k = hmac(&k, &[&v, &[0x00]]);
+        v = hmac(&k, &[&v]);
+    }
+}
+
+fn hmac(key: &[u8], chunks: &[&[u8]]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).unwrap();
+    for chunk in chunks {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Big-endian encoding of `value`, an integer below the order, in
+/// `length` octets.
+fn int_to_octets(value: &BigInt, length: usize) -> Vec<u8> {
+    int_to_big_endian(value, length).expect("Integers below the order fit in rlen octets")
+}
+
+/// Interprets `bytes` as a big-endian integer keeping only its leftmost `qlen` bits.
+fn bits_to_int(bytes: &[u8], qlen: u64) -> BigInt {
+    let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+    let blen = bytes.len() as u64 * 8;
+    if blen > qlen {
+        value >> (blen - qlen)
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secp256k1::{from_hex, N};
+    use sha2::Digest;
+
+    fn message_hash(message: &str) -> Vec<u8> {
+        Sha256::digest(message.as_bytes()).to_vec()
+    }
+
+    #[test]
+    fn rfc6979_p256_sha256_vectors() {
+        let order = from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        let secret = from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let vectors = [
+            (
+                "sample",
+                "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+            ),
+            (
+                "test",
+                "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+            ),
+        ];
+        for (message, k) in vectors {
+            assert_eq!(
+                deterministic_k(&secret, &message_hash(message), &order),
+                from_hex(k)
+            );
+        }
+    }
+
+    #[test]
+    fn secp256k1_vectors() {
+        let vectors = [
+            (
+                BigInt::from(1),
+                "Satoshi Nakamoto",
+                "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15",
+            ),
+            (
+                BigInt::from(1),
+                "All those moments will be lost in time, like tears in rain. Time to die...",
+                "38aa22d72376b4dbc472e06c3ba403ee0a394da63fc58d88686c611aba98d6b3",
+            ),
+            (
+                &*N - 1,
+                "Satoshi Nakamoto",
+                "33a19b60e25fb6f4435af53a3d42d493644827367e6453928554f43e49aa6f90",
+            ),
+            (
+                from_hex("f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181"),
+                "Alan Turing",
+                "525a82b70e67874398067543fd84c83d30c175fdc45fdeee082fe13b1d7cfdf1",
+            ),
+        ];
+        for (secret, message, k) in vectors {
+            assert_eq!(
+                deterministic_k(&secret, &message_hash(message), &N),
+                from_hex(k)
+            );
+        }
+    }
+}
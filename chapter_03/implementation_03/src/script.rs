@@ -0,0 +1,639 @@
+use crate::errors::ScriptError;
+use crate::helpers::read_bytes;
+use std::fmt;
+
+const OP_0: u8 = 0x00;
+const OP_PUSHDATA1: u8 = 0x4c;
+const OP_PUSHDATA2: u8 = 0x4d;
+const OP_PUSHDATA4: u8 = 0x4e;
+const OP_1NEGATE: u8 = 0x4f;
+const OP_1: u8 = 0x51;
+const OP_16: u8 = 0x60;
+
+/// Largest magnitude of a decimal number read from ASM, the limit of Bitcoin
+/// Core's parser. Only pushes of up to 4 bytes, i.e. up to `i32::MAX`, are
+/// rendered as numbers.
+const MAX_ASM_NUMBER: i64 = 0xffff_ffff;
+
+/// Names of the defined opcodes, as used by Bitcoin Core.
+const OPCODE_NAMES: [(u8, &str); 112] = [
+    (0x00, "OP_0"),
+    (0x4c, "OP_PUSHDATA1"),
+    (0x4d, "OP_PUSHDATA2"),
+    (0x4e, "OP_PUSHDATA4"),
+    (0x4f, "OP_1NEGATE"),
+    (0x50, "OP_RESERVED"),
+    (0x51, "OP_1"),
+    (0x52, "OP_2"),
+    (0x53, "OP_3"),
+    (0x54, "OP_4"),
+    (0x55, "OP_5"),
+    (0x56, "OP_6"),
+    (0x57, "OP_7"),
+    (0x58, "OP_8"),
+    (0x59, "OP_9"),
+    (0x5a, "OP_10"),
+    (0x5b, "OP_11"),
+    (0x5c, "OP_12"),
+    (0x5d, "OP_13"),
+    (0x5e, "OP_14"),
+    (0x5f, "OP_15"),
+    (0x60, "OP_16"),
+    (0x61, "OP_NOP"),
+    (0x62, "OP_VER"),
+    (0x63, "OP_IF"),
+    (0x64, "OP_NOTIF"),
+    (0x65, "OP_VERIF"),
+    (0x66, "OP_VERNOTIF"),
+    (0x67, "OP_ELSE"),
+    (0x68, "OP_ENDIF"),
+    (0x69, "OP_VERIFY"),
+    (0x6a, "OP_RETURN"),
+    (0x6b, "OP_TOALTSTACK"),
+    (0x6c, "OP_FROMALTSTACK"),
+    (0x6d, "OP_2DROP"),
+    (0x6e, "OP_2DUP"),
+    (0x6f, "OP_3DUP"),
+    (0x70, "OP_2OVER"),
+    (0x71, "OP_2ROT"),
+    (0x72, "OP_2SWAP"),
+    (0x73, "OP_IFDUP"),
+    (0x74, "OP_DEPTH"),
+    (0x75, "OP_DROP"),
+    (0x76, "OP_DUP"),
+    (0x77, "OP_NIP"),
+    (0x78, "OP_OVER"),
+    (0x79, "OP_PICK"),
+    (0x7a, "OP_ROLL"),
+    (0x7b, "OP_ROT"),
+    (0x7c, "OP_SWAP"),
+    (0x7d, "OP_TUCK"),
+    (0x7e, "OP_CAT"),
+    (0x7f, "OP_SUBSTR"),
+    (0x80, "OP_LEFT"),
+    (0x81, "OP_RIGHT"),
+    (0x82, "OP_SIZE"),
+    (0x83, "OP_INVERT"),
+    (0x84, "OP_AND"),
+    (0x85, "OP_OR"),
+    (0x86, "OP_XOR"),
+    (0x87, "OP_EQUAL"),
+    (0x88, "OP_EQUALVERIFY"),
+    (0x89, "OP_RESERVED1"),
+    (0x8a, "OP_RESERVED2"),
+    (0x8b, "OP_1ADD"),
+    (0x8c, "OP_1SUB"),
+    (0x8d, "OP_2MUL"),
+    (0x8e, "OP_2DIV"),
+    (0x8f, "OP_NEGATE"),
+    (0x90, "OP_ABS"),
+    (0x91, "OP_NOT"),
+    (0x92, "OP_0NOTEQUAL"),
+    (0x93, "OP_ADD"),
+    (0x94, "OP_SUB"),
+    (0x95, "OP_MUL"),
+    (0x96, "OP_DIV"),
+    (0x97, "OP_MOD"),
+    (0x98, "OP_LSHIFT"),
+    (0x99, "OP_RSHIFT"),
+    (0x9a, "OP_BOOLAND"),
+    (0x9b, "OP_BOOLOR"),
+    (0x9c, "OP_NUMEQUAL"),
+    (0x9d, "OP_NUMEQUALVERIFY"),
+    (0x9e, "OP_NUMNOTEQUAL"),
+    (0x9f, "OP_LESSTHAN"),
+    (0xa0, "OP_GREATERTHAN"),
+    (0xa1, "OP_LESSTHANOREQUAL"),
+    (0xa2, "OP_GREATERTHANOREQUAL"),
+    (0xa3, "OP_MIN"),
+    (0xa4, "OP_MAX"),
+    (0xa5, "OP_WITHIN"),
+    (0xa6, "OP_RIPEMD160"),
+    (0xa7, "OP_SHA1"),
+    (0xa8, "OP_SHA256"),
+    (0xa9, "OP_HASH160"),
+    (0xaa, "OP_HASH256"),
+    (0xab, "OP_CODESEPARATOR"),
+    (0xac, "OP_CHECKSIG"),
+    (0xad, "OP_CHECKSIGVERIFY"),
+    (0xae, "OP_CHECKMULTISIG"),
+    (0xaf, "OP_CHECKMULTISIGVERIFY"),
+    (0xb0, "OP_NOP1"),
+    (0xb1, "OP_CHECKLOCKTIMEVERIFY"),
+    (0xb2, "OP_CHECKSEQUENCEVERIFY"),
+    (0xb3, "OP_NOP4"),
+    (0xb4, "OP_NOP5"),
+    (0xb5, "OP_NOP6"),
+    (0xb6, "OP_NOP7"),
+    (0xb7, "OP_NOP8"),
+    (0xb8, "OP_NOP9"),
+    (0xb9, "OP_NOP10"),
+    (0xba, "OP_CHECKSIGADD"),
+];
+
+/// Name of `opcode`, if it is a defined opcode.
+pub fn opcode_name(opcode: u8) -> Option<&'static str> {
+    OPCODE_NAMES
+        .iter()
+        .find(|(code, _)| *code == opcode)
+        .map(|(_, name)| *name)
+}
+
+/// Opcode called `name`, e.g. `OP_CHECKSIG`.
+pub fn opcode_from_name(name: &str) -> Option<u8> {
+    OPCODE_NAMES
+        .iter()
+        .find(|(_, opcode_name)| *opcode_name == name)
+        .map(|(code, _)| *code)
+}
+
+/// A single element of a script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Any opcode that is not a data push.
+    Op(u8),
+    /// `data` pushed with `opcode`, either a direct push (0x01 to 0x4b) or
+    /// OP_PUSHDATA1/2/4. The opcode is kept so that non-minimal pushes found
+    /// on chain serialize back to the same bytes.
+    Push { opcode: u8, data: Vec<u8> },
+}
+
+impl Command {
+    /// Pushes `data` the way MINIMALDATA requires: OP_0, OP_1NEGATE and
+    /// OP_1 to OP_16 for the values they push, else the shortest push opcode.
+    pub fn push(data: Vec<u8>) -> Self {
+        let opcode = match data[..] {
+            [] => return Self::Op(OP_0),
+            [0x81] => return Self::Op(OP_1NEGATE),
+            [value @ 1..=16] => return Self::Op(OP_1 + value - 1),
+            _ => match data.len() {
+                length @ 1..=0x4b => length as u8,
+                0x4c..=0xff => OP_PUSHDATA1,
+                0x100..=0xffff => OP_PUSHDATA2,
+                _ => OP_PUSHDATA4,
+            },
+        };
+        Self::Push { opcode, data }
+    }
+}
+
+/// A script as the list of commands it is made of.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Script {
+    pub commands: Vec<Command>,
+}
+
+impl Script {
+    pub fn new(commands: Vec<Command>) -> Self {
+        Self { commands }
+    }
+
+    /// Parses the raw bytes of a script, without its length prefix.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ScriptError> {
+        let mut stream = bytes;
+        let mut commands = Vec::new();
+        while let Some((&opcode, rest)) = stream.split_first() {
+            stream = rest;
+            let length = match opcode {
+                0x01..=0x4b => opcode as usize,
+                OP_PUSHDATA1 => read_bytes(&mut stream, 1)?[0] as usize,
+                OP_PUSHDATA2 => {
+                    u16::from_le_bytes(read_bytes(&mut stream, 2)?.try_into().unwrap()) as usize
+                }
+                OP_PUSHDATA4 => {
+                    u32::from_le_bytes(read_bytes(&mut stream, 4)?.try_into().unwrap()) as usize
+                }
+                _ => {
+                    commands.push(Command::Op(opcode));
+                    continue;
+                }
+            };
+            let data = read_bytes(&mut stream, length)?.to_vec();
+            commands.push(Command::Push { opcode, data });
+        }
+        Ok(Self::new(commands))
+    }
+
+    /// Raw bytes of the script, without its length prefix.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        for command in &self.commands {
+            match command {
+                Command::Op(opcode) => result.push(*opcode),
+                Command::Push { opcode, data } => {
+                    result.push(*opcode);
+                    match *opcode {
+                        OP_PUSHDATA1 => result.push(data.len() as u8),
+                        OP_PUSHDATA2 => result.extend((data.len() as u16).to_le_bytes()),
+                        OP_PUSHDATA4 => result.extend((data.len() as u32).to_le_bytes()),
+                        _ => {}
+                    }
+                    result.extend(data);
+                }
+            }
+        }
+        result
+    }
+
+    /// Renders the script in the ASM notation of Bitcoin Core, e.g.
+    /// `OP_DUP OP_HASH160 <hex> OP_EQUALVERIFY OP_CHECKSIG` or
+    /// `2 <hex> <hex> 2 OP_CHECKMULTISIG`.
+    ///
+    /// As in Core, OP_0, OP_1NEGATE, OP_1 to OP_16 and pushes of up to 4
+    /// bytes are shown as decimal script numbers and longer pushes as hex.
+    ///
+    /// This differs from Core for undefined opcodes: they are shown as raw
+    /// `0x` bytes, where Core prints `OP_UNKNOWN`, so that they parse back.
+    pub fn to_asm(&self) -> String {
+        self.commands
+            .iter()
+            .map(|command| match command {
+                Command::Op(OP_0) => "0".to_string(),
+                Command::Op(OP_1NEGATE) => "-1".to_string(),
+                Command::Op(opcode @ OP_1..=OP_16) => (opcode - OP_1 + 1).to_string(),
+                Command::Op(opcode) => match opcode_name(*opcode) {
+                    Some(name) => name.to_string(),
+                    None => format!("0x{:02x}", opcode),
+                },
+                Command::Push { data, .. } if data.len() <= 4 => {
+                    decode_script_num(data).to_string()
+                }
+                Command::Push { data, .. } => encode_hex(data),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Parses the notation produced by `to_asm` and Bitcoin Core.
+    ///
+    /// Decimal numbers are pushed as OP_0, OP_1NEGATE, OP_1 to OP_16 or a
+    /// minimal script number, hex is pushed with `Command::push`, `0x`
+    /// tokens are inserted as raw bytes, and opcode names may drop their
+    /// `OP_` prefix.
+    ///
+    /// ASM does not record how data was pushed, so `Script` to ASM and back
+    /// is exact only for scripts whose pushes are those of `Command::push`.
+    /// Even then, a 5-byte push whose hex is all decimal digits, e.g.
+    /// `1234567890`, reads back as the number it spells, as it does in Core.
+    ///
+    /// Numbers are limited to ±0xffffffff as in Core, but this differs from
+    /// Core for other digit-only tokens: larger ones, which Core rejects, and
+    /// ones with leading zeros, which Core reads as numbers, are read as hex
+    /// pushes so that every output of `to_asm` parses back, and `-0` is
+    /// rejected.
+    pub fn from_asm(asm: &str) -> Result<Self, ScriptError> {
+        let mut bytes = Vec::new();
+        for token in asm.split_whitespace() {
+            let invalid_token = || ScriptError::InvalidAsmToken(token.to_string());
+            let push = if let Some(number) = parse_asm_number(token) {
+                Command::push(encode_script_num(number))
+            } else if let Some(raw) = token.strip_prefix("0x") {
+                bytes.extend(decode_hex(raw).ok_or_else(invalid_token)?);
+                continue;
+            } else if let Some(opcode) =
+                opcode_from_name(token).or_else(|| opcode_from_name(&format!("OP_{}", token)))
+            {
+                Command::Op(opcode)
+            } else {
+                Command::push(decode_hex(token).ok_or_else(invalid_token)?)
+            };
+            bytes.extend(Script::new(vec![push]).serialize());
+        }
+        Self::parse(&bytes)
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_asm())
+    }
+}
+
+/// `token` as a number, if it is written the way `to_asm` renders one: in
+/// decimal without leading zeros or `-0`, and within ±`MAX_ASM_NUMBER`.
+fn parse_asm_number(token: &str) -> Option<i64> {
+    let digits = token.strip_prefix('-').unwrap_or(token);
+    let canonical = token == "0" || digits.starts_with(|c: char| ('1'..='9').contains(&c));
+    if !canonical || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    token
+        .parse()
+        .ok()
+        .filter(|number: &i64| number.abs() <= MAX_ASM_NUMBER)
+}
+
+/// Minimal script number encoding of `number`: little-endian magnitude
+/// with the sign in the top bit of the last byte.
+fn encode_script_num(number: i64) -> Vec<u8> {
+    let mut bytes = number.unsigned_abs().to_le_bytes().to_vec();
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    match bytes.last() {
+        Some(&last) if last & 0x80 != 0 => bytes.push(if number < 0 { 0x80 } else { 0x00 }),
+        Some(_) if number < 0 => *bytes.last_mut().unwrap() |= 0x80,
+        _ => {}
+    }
+    bytes
+}
+
+/// Value of the script number `bytes` of at most 8 bytes, accepting
+/// non-minimal encodings.
+fn decode_script_num(bytes: &[u8]) -> i64 {
+    let Some((&last, _)) = bytes.split_last() else {
+        return 0;
+    };
+    let mut magnitude = [0_u8; 8];
+    magnitude[..bytes.len()].copy_from_slice(bytes);
+    magnitude[bytes.len() - 1] = last & 0x7f;
+    let magnitude = i64::from_le_bytes(magnitude);
+    match last & 0x80 {
+        0 => magnitude,
+        _ => -magnitude,
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.is_empty() || !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// script_pubkey of the first output of mainnet transaction 452c629d...
+    const P2PKH_SCRIPT_PUBKEY: &str = "76a914bc3b654dca7e56b04dca18f2566cdaf02e8d9ada88ac";
+
+    /// script_sig of the only input of the same transaction.
+    const P2PKH_SCRIPT_SIG: &str = "483045022100ed81ff192e75a3fd2304004dcadb746fa5e24c5031ccfcf21320b0277457c98f02207a986d955c6e0cb35d446a89d3f56100f4d7f67801c31967743a9c8e10615bed01210349fc4e631e3624a545de3f89f5d8684c7b8138bd94bdd531d2e213bf016b278a";
+
+    #[test]
+    fn parse_a_p2pkh_script_pubkey() {
+        let script = Script::parse(&hex::decode(P2PKH_SCRIPT_PUBKEY).unwrap()).unwrap();
+
+        assert_eq!(
+            script.commands,
+            vec![
+                Command::Op(0x76),
+                Command::Op(0xa9),
+                Command::Push {
+                    opcode: 0x14,
+                    data: hex::decode("bc3b654dca7e56b04dca18f2566cdaf02e8d9ada").unwrap(),
+                },
+                Command::Op(0x88),
+                Command::Op(0xac),
+            ]
+        );
+        assert_eq!(
+            script.to_asm(),
+            "OP_DUP OP_HASH160 bc3b654dca7e56b04dca18f2566cdaf02e8d9ada OP_EQUALVERIFY OP_CHECKSIG"
+        );
+    }
+
+    #[test]
+    fn mainnet_scripts_round_trip_through_bytes_and_asm() {
+        for raw in [P2PKH_SCRIPT_PUBKEY, P2PKH_SCRIPT_SIG] {
+            let raw = hex::decode(raw).unwrap();
+            let script = Script::parse(&raw).unwrap();
+
+            assert_eq!(script.serialize(), raw);
+            assert_eq!(Script::from_asm(&script.to_asm()), Ok(script));
+        }
+    }
+
+    #[test]
+    fn non_minimal_pushes_serialize_to_the_same_bytes() {
+        let pushdata1 = [vec![OP_PUSHDATA1, 0x03], vec![0xaa; 3]].concat();
+        let pushdata2 = [vec![OP_PUSHDATA2, 0x03, 0x00], vec![0xaa; 3]].concat();
+        let pushdata4 = [vec![OP_PUSHDATA4, 0x03, 0x00, 0x00, 0x00], vec![0xaa; 3]].concat();
+        for raw in [pushdata1, pushdata2, pushdata4] {
+            let script = Script::parse(&raw).unwrap();
+
+            assert_eq!(script.commands.len(), 1);
+            assert_eq!(script.serialize(), raw);
+            assert_eq!(script.to_asm(), "-2796202");
+        }
+    }
+
+    #[test]
+    fn asm_pushes_use_the_shortest_opcode() {
+        let pushes = [
+            (1, 0x01),
+            (0x4b, 0x4b),
+            (0x4c, OP_PUSHDATA1),
+            (0xff, OP_PUSHDATA1),
+            (0x100, OP_PUSHDATA2),
+            (0x10000, OP_PUSHDATA4),
+        ];
+        for (length, opcode) in pushes {
+            let data = vec![0x5a; length];
+            let script = Script::from_asm(&encode_hex(&data)).unwrap();
+
+            assert_eq!(script.commands, vec![Command::Push { opcode, data }]);
+        }
+        assert_eq!(Command::push(Vec::new()), Command::Op(OP_0));
+    }
+
+    #[test]
+    fn small_numbers_are_pushed_with_their_opcodes() {
+        assert_eq!(Command::push(vec![0x81]), Command::Op(OP_1NEGATE));
+        assert_eq!(Command::push(vec![0x01]), Command::Op(OP_1));
+        assert_eq!(Command::push(vec![0x10]), Command::Op(OP_16));
+        assert_eq!(
+            Command::push(vec![0x00]),
+            Command::Push {
+                opcode: 0x01,
+                data: vec![0x00]
+            }
+        );
+        assert_eq!(
+            Command::push(vec![0x11]),
+            Command::Push {
+                opcode: 0x01,
+                data: vec![0x11]
+            }
+        );
+    }
+
+    #[test]
+    fn numbers_are_rendered_and_parsed_in_decimal() {
+        let numbers = [
+            (0, ""),
+            (-1, "81"),
+            (1, "01"),
+            (16, "10"),
+            (17, "11"),
+            (-17, "91"),
+            (127, "7f"),
+            (128, "8000"),
+            (-128, "8080"),
+            (1000, "e803"),
+            (i32::MAX as i64, "ffffff7f"),
+            (-(i32::MAX as i64), "ffffffff"),
+        ];
+        for (number, encoded) in numbers {
+            let command = Command::push(hex::decode(encoded).unwrap());
+            let script = Script::new(vec![command]);
+
+            assert_eq!(encode_script_num(number), hex::decode(encoded).unwrap());
+            assert_eq!(script.to_asm(), number.to_string());
+            assert_eq!(Script::from_asm(&number.to_string()), Ok(script));
+        }
+    }
+
+    #[test]
+    fn non_minimal_numbers_are_rendered_by_value() {
+        for (raw, asm) in [
+            ("0100", "0"),
+            ("0180", "0"),
+            ("0181", "-1"),
+            ("020100", "1"),
+        ] {
+            let script = Script::parse(&hex::decode(raw).unwrap()).unwrap();
+
+            assert_eq!(script.to_asm(), asm);
+        }
+    }
+
+    #[test]
+    fn core_asm_examples() {
+        let hash = "751e76e8199196d454941c45d1b3a323f1433bd6";
+        let pubkey1 = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let pubkey2 = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+        let p2wpkh = format!("0014{}", hash);
+        let multisig = format!("5221{}21{}52ae", pubkey1, pubkey2);
+        let examples = [
+            (p2wpkh, format!("0 {}", hash)),
+            (
+                multisig,
+                format!("2 {} {} 2 OP_CHECKMULTISIG", pubkey1, pubkey2),
+            ),
+        ];
+        for (raw, asm) in examples {
+            let script = Script::parse(&hex::decode(raw).unwrap()).unwrap();
+
+            assert_eq!(script.to_asm(), asm);
+            assert_eq!(Script::from_asm(&asm), Ok(script));
+        }
+        assert_eq!(
+            Script::from_asm("DUP HASH160"),
+            Script::from_asm("OP_DUP OP_HASH160")
+        );
+    }
+
+    #[test]
+    fn five_byte_pushes_spelled_in_decimal_read_back_as_numbers() {
+        let script = Script::new(vec![Command::push(hex::decode("1234567890").unwrap())]);
+        let number = Script::new(vec![Command::push(encode_script_num(1234567890))]);
+
+        assert_eq!(script.to_asm(), "1234567890");
+        assert_eq!(Script::from_asm("1234567890"), Ok(number));
+    }
+
+    #[test]
+    fn numbers_up_to_core_limit_are_parsed() {
+        let numbers = [
+            ("2147483648", "0000008000"),
+            ("4294967295", "ffffffff00"),
+            ("-4294967295", "ffffffff80"),
+        ];
+        for (asm, encoded) in numbers {
+            let script = Script::from_asm(asm).unwrap();
+
+            assert_eq!(
+                script,
+                Script::new(vec![Command::push(hex::decode(encoded).unwrap())])
+            );
+            assert_eq!(script.to_asm(), encoded);
+        }
+        assert_eq!(
+            Script::from_asm("4294967296"),
+            Ok(Script::new(vec![Command::push(
+                hex::decode("4294967296").unwrap()
+            )]))
+        );
+    }
+
+    #[test]
+    fn undefined_opcodes_are_rendered_as_raw_bytes() {
+        let script = Script::parse(&[0x51, 0xff, 0xbb]).unwrap();
+
+        assert_eq!(script.to_asm(), "1 0xff 0xbb");
+        assert_eq!(Script::from_asm("1 0xff 0xbb"), Ok(script));
+    }
+
+    #[test]
+    fn reject_truncated_pushes() {
+        for raw in ["05aabb", "4c", "4c02aa", "4d01", "4e010000"] {
+            assert_eq!(
+                Script::parse(&hex::decode(raw).unwrap()),
+                Err(ScriptError::UnexpectedEnd)
+            );
+        }
+        assert_eq!(
+            Script::from_asm("0x4c05aa"),
+            Err(ScriptError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn reject_invalid_asm_tokens() {
+        for token in ["OP_NOPE", "abc", "zz", "0x", "0xzz", "-0", "-", "-12a"] {
+            assert_eq!(
+                Script::from_asm(&format!("OP_DUP {}", token)),
+                Err(ScriptError::InvalidAsmToken(token.to_string()))
+            );
+        }
+    }
+
+    fn minimal_command() -> impl Strategy<Value = Command> {
+        prop_oneof![
+            prop_oneof![Just(OP_0), OP_1NEGATE..=u8::MAX].prop_map(Command::Op),
+            (-(i32::MAX as i64)..=i32::MAX as i64)
+                .prop_map(|number| Command::push(encode_script_num(number))),
+            (
+                0xa0..=u8::MAX,
+                proptest::collection::vec(any::<u8>(), 4..100)
+            )
+                .prop_map(|(first, rest)| Command::push([vec![first], rest].concat())),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn minimal_scripts_round_trip_through_asm(
+            commands in proptest::collection::vec(minimal_command(), 0..10)
+        ) {
+            let script = Script::new(commands);
+
+            prop_assert_eq!(Script::from_asm(&script.to_asm()), Ok(script));
+        }
+
+        #[test]
+        fn script_numbers_round_trip(number in -MAX_ASM_NUMBER..=MAX_ASM_NUMBER) {
+            prop_assert_eq!(decode_script_num(&encode_script_num(number)), number);
+        }
+
+        #[test]
+        fn parsed_scripts_serialize_to_the_same_bytes(
+            raw in proptest::collection::vec(any::<u8>(), 0..200)
+        ) {
+            if let Ok(script) = Script::parse(&raw) {
+                prop_assert_eq!(script.serialize(), raw);
+                prop_assert_eq!(Script::from_asm(&script.to_asm()).map(|s| s.to_asm()), Ok(script.to_asm()));
+            }
+        }
+    }
+}
@@ -0,0 +1,544 @@
+use crate::curve::Curve;
+use crate::errors::SecError;
+use crate::field::Field;
+use crate::field_element::FieldElement;
+use crate::helpers::{big_endian_to_int, int_to_big_endian};
+use crate::jacobian_point::JacobianPoint;
+use crate::point::Point;
+use crate::projective_point::ProjectivePoint;
+use num_bigint::{BigInt, BigUint};
+use std::ops::{Add, Div, Mul, Sub};
+use std::sync::LazyLock;
+
+/// Prime of the field secp256k1 is defined over: 2^256 - 2^32 - 977.
+pub static P: LazyLock<BigInt> =
+    LazyLock::new(|| (BigInt::from(1) << 256) - (BigInt::from(1) << 32) - BigInt::from(977));
+
+/// Order of the group generated by `G`.
+pub static N: LazyLock<BigInt> =
+    LazyLock::new(|| from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"));
+
+/// Generator point of secp256k1.
+pub static G: LazyLock<S256Point> = LazyLock::new(|| {
+    S256Point::new_point(
+        S256Field::new(from_hex(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        )),
+        S256Field::new(from_hex(
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        )),
+    )
+    .unwrap()
+});
+
+/// The curve y^2 = x^3 + 7 over the field of order `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    type Field = S256Field;
+
+    fn a() -> Self::Field {
+        S256Field::from(0)
+    }
+
+    fn b() -> Self::Field {
+        S256Field::from(7)
+    }
+
+    fn generator() -> S256Point {
+        G.clone()
+    }
+
+    fn order() -> BigInt {
+        N.clone()
+    }
+
+    fn cofactor() -> BigInt {
+        BigInt::from(1)
+    }
+}
+
+/// Point on secp256k1.
+pub type S256Point = Point<Secp256k1>;
+
+/// Bits of the scalar covered by each row of `GENERATOR_TABLE`.
+const GENERATOR_WINDOW: usize = 4;
+
+/// Row `i` holds j·2^(4i)·G for j in 1..16, so k·G is the sum of one entry
+/// per 4-bit digit of k and needs no doublings. Built on first use.
+static GENERATOR_TABLE: LazyLock<Vec<Vec<S256Point>>> = LazyLock::new(|| {
+    let row_length = (1 << GENERATOR_WINDOW) - 1;
+    let mut jacobian_entries = Vec::new();
+    let mut row_base = JacobianPoint::from(G.clone());
+    for _ in 0..256 / GENERATOR_WINDOW {
+        let mut entry = row_base.clone();
+        for _ in 0..row_length {
+            jacobian_entries.push(entry.clone());
+            entry = entry.add_jacobian(&row_base);
+        }
+        row_base = entry;
+    }
+    JacobianPoint::batch_to_affine(&jacobian_entries)
+        .chunks(row_length)
+        .map(<[S256Point]>::to_vec)
+        .collect()
+});
+
+/// `GENERATOR_TABLE` in projective coordinates with the point at infinity
+/// as entry 0 of every row, for `mul_generator_ct`.
+static GENERATOR_TABLE_CT: LazyLock<Vec<Vec<ProjectivePoint>>> = LazyLock::new(|| {
+    GENERATOR_TABLE
+        .iter()
+        .map(|row| {
+            let mut entries = vec![ProjectivePoint::new_infinity()];
+            entries.extend(row.iter().map(ProjectivePoint::from_affine));
+            entries
+        })
+        .collect()
+});
+
+pub(crate) fn from_hex(hex: &str) -> BigInt {
+    BigInt::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
+
+/// 32-byte big-endian encoding of `value` modulo N.
+pub(crate) fn scalar_bytes(value: &BigInt) -> [u8; 32] {
+    int_to_big_endian(&mod_n(value), 32)
+        .expect("Values reduced modulo N fit in 32 bytes")
+        .try_into()
+        .unwrap()
+}
+
+/// Reduces `value` into the range [0, N).
+pub fn mod_n(value: &BigInt) -> BigInt {
+    ((value % &*N) + &*N) % &*N
+}
+
+/// Inverse of `value` modulo N, using Fermat's little theorem.
+pub fn inverse_mod_n(value: &BigInt) -> BigInt {
+    mod_n(value).modpow(&(&*N - 2), &N)
+}
+
+/// Element of the field of order `P`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S256Field(FieldElement);
+
+impl S256Field {
+    pub fn new(num: BigInt) -> Self {
+        Self(FieldElement::new(num, P.clone()))
+    }
+
+    pub fn num(&self) -> &BigInt {
+        self.0.num()
+    }
+
+    /// Big-endian encoding of the element in 32 bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        int_to_big_endian(self.num(), 32)
+            .expect("Field elements are below P")
+            .try_into()
+            .unwrap()
+    }
+}
+
+impl S256Point {
+    /// Computes k·P for a secret `k` with the constant-time Montgomery ladder
+    /// of `ProjectivePoint`, after reducing `k` modulo N.
+    pub fn scalar_mul_ct(&self, k: &BigInt) -> Self {
+        ProjectivePoint::from_affine(self)
+            .ladder(&scalar_bytes(k))
+            .to_affine()
+    }
+
+    /// Computes k·G with the precomputed generator table.
+    ///
+    /// Which entries are read depends on `k`, so it is only for public
+    /// scalars; secret ones go through `mul_generator_ct`.
+    pub fn mul_generator(k: &BigInt) -> Self {
+        let k = mod_n(k);
+        let digit_mask = BigInt::from((1 << GENERATOR_WINDOW) - 1);
+        let mut result = JacobianPoint::new_infinity();
+        for (i, row) in GENERATOR_TABLE.iter().enumerate() {
+            let digit: usize = ((&k >> (i * GENERATOR_WINDOW)) & &digit_mask)
+                .try_into()
+                .unwrap();
+            if digit != 0 {
+                result = result.add_affine(&row[digit - 1]);
+            }
+        }
+        result.to_affine()
+    }
+
+    /// Computes k·G for a secret `k` with the same table as `mul_generator`.
+    ///
+    /// Each row is read in full with `ProjectivePoint::select` and its entry
+    /// added with complete formulas, zero digits included, so neither the
+    /// memory accessed nor the operations depend on `k`.
+    pub fn mul_generator_ct(k: &BigInt) -> Self {
+        let k = scalar_bytes(k);
+        let mut result = ProjectivePoint::new_infinity();
+        for (i, row) in GENERATOR_TABLE_CT.iter().enumerate() {
+            let digit = (k[31 - i / 2] >> (GENERATOR_WINDOW * (i % 2))) & 0x0f;
+            result = result.add_complete(&ProjectivePoint::select(row, digit as u64));
+        }
+        result.to_affine()
+    }
+
+    /// SEC1 encoding of the point, 33 bytes when `compressed` and 65 otherwise.
+    /// The point at infinity is encoded as a single zero byte, which
+    /// `parse_sec` rejects.
+    pub fn to_sec(&self, compressed: bool) -> Vec<u8> {
+        match self {
+            Self::Infinity => vec![0x00],
+            Self::Point(x, y) if compressed => {
+                let prefix = if y.num().bit(0) { 0x03 } else { 0x02 };
+                let mut sec = vec![prefix];
+                sec.extend_from_slice(&x.to_bytes());
+                sec
+            }
+            Self::Point(x, y) => {
+                let mut sec = vec![0x04];
+                sec.extend_from_slice(&x.to_bytes());
+                sec.extend_from_slice(&y.to_bytes());
+                sec
+            }
+        }
+    }
+
+    /// Parses a compressed or uncompressed SEC1 encoded public key.
+    ///
+    /// The point at infinity is refused: every signature verifies against
+    /// it once r and s are in range.
+    pub fn parse_sec(sec: &[u8]) -> Result<Self, SecError> {
+        match (sec.first(), sec.len()) {
+            (Some(0x00), 1) => Err(SecError::PointAtInfinity),
+            (Some(0x04), 65) => {
+                let x = parse_coordinate(&sec[1..33])?;
+                let y = parse_coordinate(&sec[33..])?;
+                Self::try_new(x, y).map_err(|_| SecError::NotOnCurve)
+            }
+            (Some(&prefix @ (0x02 | 0x03)), 33) => {
+                let x = parse_coordinate(&sec[1..])?;
+                let alpha = x.clone() * x.clone() * x.clone() + S256Field::from(7);
+                // P % 4 == 3, so the root is alpha^((P + 1) / 4)
+                let beta = alpha.sqrt().ok_or(SecError::NotOnCurve)?;
+                let y = if beta.num().bit(0) == (prefix == 0x03) {
+                    beta
+                } else {
+                    S256Field::from(0) - beta
+                };
+                Ok(Self::Point(x, y))
+            }
+            (Some(0x02..=0x04), length) => Err(SecError::InvalidLength(length)),
+            (Some(&prefix), _) => Err(SecError::InvalidPrefix(prefix)),
+            (None, _) => Err(SecError::InvalidLength(0)),
+        }
+    }
+}
+
+fn parse_coordinate(bytes: &[u8]) -> Result<S256Field, SecError> {
+    let num = big_endian_to_int(bytes);
+    if num >= *P {
+        return Err(SecError::CoordinateOutOfRange);
+    }
+    Ok(S256Field::new(num))
+}
+
+impl From<i64> for S256Field {
+    fn from(value: i64) -> Self {
+        let num = BigInt::from(value) % &*P;
+        if num < BigInt::from(0) {
+            Self::new(num + &*P)
+        } else {
+            Self::new(num)
+        }
+    }
+}
+
+impl Field for S256Field {
+    fn zero(&self) -> Self {
+        Self(self.0.zero())
+    }
+
+    fn one(&self) -> Self {
+        Self(self.0.one())
+    }
+
+    fn modulus(&self) -> BigUint {
+        self.0.modulus()
+    }
+
+    fn element_from_bytes(&self, bytes: &[u8]) -> Option<Self> {
+        self.0.element_from_bytes(bytes).map(Self)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        Field::to_bytes(&self.0)
+    }
+
+    fn pow(&self, exponent: &BigUint) -> Self {
+        Self(Field::pow(&self.0, exponent))
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Self)
+    }
+}
+
+impl Add for S256Field {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for S256Field {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for S256Field {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for S256Field {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::CurveError;
+
+    fn s256_point(x: &str, y: &str) -> S256Point {
+        S256Point::new_point(S256Field::new(from_hex(x)), S256Field::new(from_hex(y))).unwrap()
+    }
+
+    #[test]
+    fn prime_has_the_expected_value() {
+        assert_eq!(
+            *P,
+            from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+        );
+    }
+
+    #[test]
+    fn negative_integers_are_reduced_into_the_field() {
+        assert_eq!(S256Field::from(-1), S256Field::new(&*P - 1));
+    }
+
+    #[test]
+    fn generator_is_on_the_curve() {
+        let (x, y) = match &*G {
+            S256Point::Point(x, y) => (x.clone(), y.clone()),
+            S256Point::Infinity => panic!("G can not be the point at infinity"),
+        };
+        assert!(S256Point::new_point(x, y).is_ok());
+    }
+
+    #[test]
+    fn small_multiples_of_the_generator() {
+        let multiples = [
+            (
+                "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+                "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+            ),
+            (
+                "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+                "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+            ),
+            (
+                "e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13",
+                "51ed993ea0d455b75642e2098ea51448d967ae33bfbdfe40cfe97bdc47739922",
+            ),
+            (
+                "2f8bde4d1a07209355b4a7250a5c5128e88b84bddc619ab7cba8d569b240efe4",
+                "d8ac222636e5e3d6d4dba9dda6c9c426f788271bab0d6840dca87d3aa6ac62d6",
+            ),
+        ];
+
+        let mut multiple = G.clone();
+        for (x, y) in multiples {
+            multiple = multiple + G.clone();
+            assert_eq!(multiple, s256_point(x, y));
+        }
+    }
+
+    #[test]
+    fn generator_plus_its_negation_is_infinity() {
+        let negated_g = match &*G {
+            S256Point::Point(x, y) => {
+                S256Point::new_point(x.clone(), S256Field::from(0) - y.clone())
+            }
+            S256Point::Infinity => panic!("G can not be the point at infinity"),
+        };
+        assert_eq!(G.clone() + negated_g.unwrap(), S256Point::new_infinity());
+    }
+
+    #[test]
+    fn known_multiples_of_the_generator() {
+        let multiples = [
+            (
+                BigInt::from(7),
+                "5cbdf0646e5db4eaa398f365f2ea7a0e3d419b7e0330e39ce92bddedcac4f9bc",
+                "6aebca40ba255960a3178d6d861a54dba813d0b813fde7b5a5082628087264da",
+            ),
+            (
+                BigInt::from(1485),
+                "c982196a7466fbbbb0e27a940b6af926c1a74d5ad07128c82824a11b5398afda",
+                "7a91f9eae64438afb9ce6448a1c133db2d8fb9254e4546b6f001637d50901f55",
+            ),
+            (
+                BigInt::from(1) << 128,
+                "8f68b9d2f63b5f339239c1ad981f162ee88c5678723ea3351b7b444c9ec4c0da",
+                "662a9f2dba063986de1d90c2b6be215dbbea2cfe95510bfdf23cbf79501fff82",
+            ),
+            (
+                (BigInt::from(1) << 240) + (BigInt::from(1) << 31),
+                "9577ff57c8234558f293df502ca4f09cbc65a6572c842b39b366f21717945116",
+                "10b49c67fa9365ad7b90dab070be339a1daf9052373ec30ffae4f72d5e66d053",
+            ),
+        ];
+        for (k, x, y) in multiples {
+            assert_eq!(G.scalar_mul(&k), s256_point(x, y));
+            assert_eq!(G.scalar_mul_ct(&k), s256_point(x, y));
+            assert_eq!(S256Point::mul_generator(&k), s256_point(x, y));
+            assert_eq!(S256Point::mul_generator_ct(&k), s256_point(x, y));
+            assert_eq!(G.mul_wnaf(&k), s256_point(x, y));
+        }
+    }
+
+    #[test]
+    fn order_times_generator_is_infinity() {
+        assert_eq!(G.clone() * N.clone(), S256Point::new_infinity());
+        assert_eq!(G.scalar_mul_ct(&N), S256Point::new_infinity());
+        assert_eq!(G.scalar_mul_ct(&(&*N - 1)), G.negate());
+    }
+
+    #[test]
+    fn order_minus_one_times_generator_is_the_negated_generator() {
+        assert_eq!(G.scalar_mul(&(&*N - 1)), G.negate());
+        assert_eq!(S256Point::mul_generator(&(&*N - 1)), G.negate());
+        assert_eq!(S256Point::mul_generator(&N), S256Point::new_infinity());
+        assert_eq!(S256Point::mul_generator(&BigInt::from(-1)), G.negate());
+        assert_eq!(S256Point::mul_generator_ct(&(&*N - 1)), G.negate());
+        assert_eq!(S256Point::mul_generator_ct(&N), S256Point::new_infinity());
+        assert_eq!(S256Point::mul_generator_ct(&BigInt::from(-1)), G.negate());
+    }
+
+    #[test]
+    fn uncompressed_sec_round_trip() {
+        let vectors = [
+            (
+                BigInt::from(999).pow(3),
+                "049d5ca49670cbe4c3bfa84c96a8c87df086c6ea6a24ba6b809c9de234496808d56fa15cc7f3d38cda98dee2419f415b7513dde1301f8643cd9245aea7f3f911f9",
+            ),
+            (
+                BigInt::from(123),
+                "04a598a8030da6d86c6bc7f2f5144ea549d28211ea58faa70ebf4c1e665c1fe9b5204b5d6f84822c307e4b4a7140737aec23fc63b65b35f86a10026dbd2d864e6b",
+            ),
+            (
+                BigInt::from(42424242),
+                "04aee2e7d843f7430097859e2bc603abcc3274ff8169c1a469fee0f20614066f8e21ec53f40efac47ac1c5211b2123527e0e9b57ede790c4da1e72c91fb7da54a3",
+            ),
+        ];
+        for (secret, sec) in vectors {
+            let point = G.scalar_mul(&secret);
+            let sec = hex::decode(sec).unwrap();
+
+            assert_eq!(point.to_sec(false), sec);
+            assert_eq!(S256Point::parse_sec(&sec).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn compressed_sec_round_trip() {
+        let vectors = [
+            (
+                BigInt::from(5001),
+                "0357a4f368868a8a6d572991e484e664810ff14c05c0fa023275251151fe0e53d1",
+            ),
+            (
+                BigInt::from(2019).pow(5),
+                "02933ec2d2b111b92737ec12f1c5d20f3233a0ad21cd8b36d0bca7a0cfa5cb8701",
+            ),
+            (
+                from_hex("deadbeef54321"),
+                "0296be5b1292f6c856b3c5654e886fc13511462059089cdf9c479623bfcbe77690",
+            ),
+        ];
+        for (secret, sec) in vectors {
+            let point = G.scalar_mul(&secret);
+            let sec = hex::decode(sec).unwrap();
+
+            assert_eq!(point.to_sec(true), sec);
+            assert_eq!(S256Point::parse_sec(&sec).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn invalid_sec_encodings_are_rejected() {
+        let compressed_g = G.to_sec(true);
+        let mut wrong_prefix = compressed_g.clone();
+        wrong_prefix[0] = 0x05;
+        let mut off_curve = G.to_sec(false);
+        off_curve[64] ^= 0x01;
+        let mut x_too_big = vec![0x02];
+        x_too_big.extend_from_slice(&[0xff; 32]);
+        // x = 5 gives x^3 + 7 = 132, which has no square root modulo P
+        let mut no_square_root = vec![0x02];
+        no_square_root.extend_from_slice(&S256Field::from(5).to_bytes());
+
+        assert_eq!(S256Point::parse_sec(&[]), Err(SecError::InvalidLength(0)));
+        assert_eq!(
+            S256Point::parse_sec(&S256Point::new_infinity().to_sec(true)),
+            Err(SecError::PointAtInfinity)
+        );
+        assert_eq!(
+            S256Point::parse_sec(&[0x00; 33]),
+            Err(SecError::InvalidPrefix(0x00))
+        );
+        assert_eq!(
+            S256Point::parse_sec(&compressed_g[..32]),
+            Err(SecError::InvalidLength(32))
+        );
+        assert_eq!(
+            S256Point::parse_sec(&wrong_prefix),
+            Err(SecError::InvalidPrefix(0x05))
+        );
+        assert_eq!(S256Point::parse_sec(&off_curve), Err(SecError::NotOnCurve));
+        assert_eq!(
+            S256Point::parse_sec(&x_too_big),
+            Err(SecError::CoordinateOutOfRange)
+        );
+        assert_eq!(
+            S256Point::parse_sec(&no_square_root),
+            Err(SecError::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn sec_errors_convert_into_curve_errors() {
+        let mut off_curve = G.to_sec(false);
+        off_curve[64] ^= 0x01;
+        let parse =
+            |sec: &[u8]| -> Result<S256Point, CurveError> { Ok(S256Point::parse_sec(sec)?) };
+
+        assert_eq!(parse(&G.to_sec(true)), Ok(G.clone()));
+        assert_eq!(parse(&off_curve), Err(CurveError::NotOnCurve));
+        assert_eq!(parse(&[0x02]), Err(CurveError::InvalidEncoding));
+    }
+}
@@ -0,0 +1,299 @@
+use crate::errors::{DerError, SignatureOutOfRangeError};
+use crate::jacobian_point::JacobianPoint;
+use crate::public_key::PublicKey;
+use crate::secp256k1::{S256Field, N, P};
+use num_bigint::{BigInt, Sign};
+use std::fmt;
+
+/// ECDSA signature over secp256k1, with r and s in the range 1 to N - 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    r: BigInt,
+    s: BigInt,
+}
+
+impl Signature {
+    pub fn new(r: BigInt, s: BigInt) -> Result<Self, SignatureOutOfRangeError> {
+        let in_range = |value: &BigInt| value >= &BigInt::from(1) && value < &*N;
+        if !in_range(&r) || !in_range(&s) {
+            return Err(SignatureOutOfRangeError);
+        }
+        Ok(Self { r, s })
+    }
+
+    pub fn r(&self) -> &BigInt {
+        &self.r
+    }
+
+    pub fn s(&self) -> &BigInt {
+        &self.s
+    }
+
+    /// Checks every signature in `batch` against its public key and message
+    /// hash, returning whether all of them are valid.
+    ///
+    /// ECDSA signatures only keep the x-coordinate of R, so unlike Schnorr
+    /// signatures they can not be combined into one random linear
+    /// combination. Instead the precomputations of every u·G + v·P share a
+    /// single field inversion and the sums are compared to r in Jacobian
+    /// coordinates.
+    pub fn verify_batch(batch: &[(PublicKey, BigInt, Signature)]) -> bool {
+        let mut terms = Vec::with_capacity(batch.len());
+        for (public_key, z, signature) in batch {
+            match public_key.verification_terms(z, signature) {
+                Some(signature_terms) => terms.push(signature_terms),
+                None => return false,
+            }
+        }
+        JacobianPoint::multi_mul_batch(&terms)
+            .iter()
+            .zip(batch)
+            .all(|(total, (_, _, signature))| {
+                // x is below P, so it is r modulo N when it is r or r + N
+                let r_plus_n = &signature.r + &*N;
+                total.has_affine_x(&S256Field::new(signature.r.clone()))
+                    || (r_plus_n < *P && total.has_affine_x(&S256Field::new(r_plus_n)))
+            })
+    }
+
+    /// DER encoding of the signature, without the sighash byte.
+    ///
+    /// r and s are positive and below N, so each takes at most 33 bytes and
+    /// the whole sequence fits a one-byte length.
+    pub fn to_der(&self) -> Vec<u8> {
+        let r = encode_integer(&self.r);
+        let s = encode_integer(&self.s);
+        let mut der = vec![0x30, (r.len() + s.len()) as u8];
+        der.extend(r);
+        der.extend(s);
+        der
+    }
+
+    /// Parses a DER signature (without the sighash byte) following the
+    /// strict encoding rules of BIP66, and rejects r or s outside the range
+    /// 1 to N - 1.
+    pub fn parse_der(der: &[u8]) -> Result<Self, DerError> {
+        let length = der.len();
+        if !(8..=72).contains(&length) {
+            return Err(DerError::InvalidLength(length));
+        }
+        if der[0] != 0x30 {
+            return Err(DerError::InvalidSequenceMarker(der[0]));
+        }
+        if der[1] as usize != length - 2 {
+            return Err(DerError::LengthMismatch);
+        }
+        let r_length = der[3] as usize;
+        if 5 + r_length >= length {
+            return Err(DerError::LengthMismatch);
+        }
+        let s_length = der[5 + r_length] as usize;
+        if r_length + s_length + 6 != length {
+            return Err(DerError::LengthMismatch);
+        }
+        let r = parse_integer(der[2], &der[4..4 + r_length])?;
+        let s = parse_integer(der[4 + r_length], &der[6 + r_length..])?;
+        Ok(Self::new(r, s)?)
+    }
+}
+
+fn encode_integer(value: &BigInt) -> Vec<u8> {
+    let (_, bytes) = value.to_bytes_be();
+    let mut encoded = vec![0x02];
+    if bytes[0] & 0x80 != 0 {
+        encoded.push(bytes.len() as u8 + 1);
+        encoded.push(0x00);
+    } else {
+        encoded.push(bytes.len() as u8);
+    }
+    encoded.extend(bytes);
+    encoded
+}
+
+fn parse_integer(marker: u8, bytes: &[u8]) -> Result<BigInt, DerError> {
+    if marker != 0x02 {
+        return Err(DerError::InvalidIntegerMarker(marker));
+    }
+    match bytes {
+        [] => Err(DerError::ZeroLengthInteger),
+        [first, ..] if first & 0x80 != 0 => Err(DerError::NegativeInteger),
+        [0x00, second, ..] if second & 0x80 == 0 => Err(DerError::ExcessivePadding),
+        _ => Ok(BigInt::from_bytes_be(Sign::Plus, bytes)),
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Signature({:x},{:x})", self.r, self.s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::private_key::PrivateKey;
+    use crate::secp256k1::from_hex;
+
+    const BOOK_DER: &str = "3045022037206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c60221008ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec";
+
+    fn book_signature() -> Signature {
+        Signature::new(
+            from_hex("37206a0610995c58074999cb9767b87af4c4978db68c06e8e6e81d282047a7c6"),
+            from_hex("8ca63759c1157ebeaec0d03cecca119fc9a75bf8e6d0fa65c841c8e2738cdaec"),
+        )
+        .unwrap()
+    }
+
+    fn signed_batch() -> Vec<(PublicKey, BigInt, Signature)> {
+        [12345, 54321, 0xdeadbeef_u32]
+            .into_iter()
+            .enumerate()
+            .map(|(i, secret)| {
+                let private_key = PrivateKey::new(BigInt::from(secret)).unwrap();
+                let z =
+                    from_hex("969f6056aa26f7d2795fd013fe88868d09c9f6aed96965016e1936ae47060d48")
+                        + i;
+                let signature = private_key.sign(&z).unwrap();
+                (private_key.public_key().clone(), z, signature)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn verify_a_batch_of_valid_signatures() {
+        let batch = signed_batch();
+
+        assert!(Signature::verify_batch(&batch));
+        for (public_key, z, signature) in &batch {
+            assert!(public_key.verify(z, signature));
+        }
+        assert!(Signature::verify_batch(&[]));
+    }
+
+    #[test]
+    fn reject_a_batch_with_one_invalid_signature() {
+        let mut tampered = signed_batch();
+        let signature = &tampered[1].2;
+        tampered[1].2 = Signature::new(signature.r.clone(), &signature.s + 1).unwrap();
+        let mut wrong_message = signed_batch();
+        wrong_message[2].1 += 1;
+        let mut wrong_key = signed_batch();
+        wrong_key[0].0 = wrong_key[1].0.clone();
+
+        for batch in [tampered, wrong_message, wrong_key] {
+            assert!(!Signature::verify_batch(&batch));
+        }
+    }
+
+    #[test]
+    fn encode_a_signature_in_der() {
+        assert_eq!(book_signature().to_der(), hex::decode(BOOK_DER).unwrap());
+    }
+
+    #[test]
+    fn parse_a_der_signature() {
+        let der = hex::decode(BOOK_DER).unwrap();
+
+        assert_eq!(Signature::parse_der(&der), Ok(book_signature()));
+    }
+
+    #[test]
+    fn der_round_trip_with_small_values() {
+        for (r, s) in [(1, 2), (0x80, 0x7f), (0xff00, 0x10000)] {
+            let signature = Signature::new(BigInt::from(r), BigInt::from(s)).unwrap();
+
+            assert_eq!(Signature::parse_der(&signature.to_der()), Ok(signature));
+        }
+    }
+
+    #[test]
+    fn values_out_of_range_are_rejected() {
+        let one = BigInt::from(1);
+        for (r, s) in [
+            (BigInt::from(0), one.clone()),
+            (one.clone(), BigInt::from(0)),
+            (N.clone(), one.clone()),
+            (one.clone(), N.clone()),
+            (BigInt::from(-1), one.clone()),
+        ] {
+            assert_eq!(Signature::new(r, s), Err(SignatureOutOfRangeError));
+        }
+        assert!(Signature::new(&*N - 1, &*N - 1).is_ok());
+
+        let zero_r = hex::decode("3006020100020101").unwrap();
+        let mut n_as_s = hex::decode("3026020101022100").unwrap();
+        n_as_s.extend(N.to_bytes_be().1);
+
+        assert_eq!(Signature::parse_der(&zero_r), Err(DerError::OutOfRange));
+        assert_eq!(Signature::parse_der(&n_as_s), Err(DerError::OutOfRange));
+    }
+
+    #[test]
+    fn reject_non_strict_der_encodings() {
+        let der = hex::decode(BOOK_DER).unwrap();
+        let with_sighash = [der.clone(), vec![0x01]].concat();
+        let mut wrong_sequence = der.clone();
+        wrong_sequence[0] = 0x31;
+        let mut wrong_total_length = der.clone();
+        wrong_total_length[1] = 0x44;
+        let mut wrong_r_marker = der.clone();
+        wrong_r_marker[2] = 0x03;
+        let mut wrong_s_marker = der.clone();
+        wrong_s_marker[36] = 0x03;
+
+        assert_eq!(
+            Signature::parse_der(&der[..7]),
+            Err(DerError::InvalidLength(7))
+        );
+        assert_eq!(
+            Signature::parse_der(&with_sighash),
+            Err(DerError::LengthMismatch)
+        );
+        assert_eq!(
+            Signature::parse_der(&wrong_sequence),
+            Err(DerError::InvalidSequenceMarker(0x31))
+        );
+        assert_eq!(
+            Signature::parse_der(&wrong_total_length),
+            Err(DerError::LengthMismatch)
+        );
+        assert_eq!(
+            Signature::parse_der(&wrong_r_marker),
+            Err(DerError::InvalidIntegerMarker(0x03))
+        );
+        assert_eq!(
+            Signature::parse_der(&wrong_s_marker),
+            Err(DerError::InvalidIntegerMarker(0x03))
+        );
+    }
+
+    #[test]
+    fn reject_malformed_integers() {
+        let empty_r = hex::decode("3006020002020101").unwrap();
+        let negative_r = hex::decode("3006020180020101").unwrap();
+        let padded_r = hex::decode("300702020001020101").unwrap();
+        let negative_s = hex::decode("3006020101020180").unwrap();
+        let padded_s = hex::decode("300702010102020001").unwrap();
+
+        assert_eq!(
+            Signature::parse_der(&empty_r),
+            Err(DerError::ZeroLengthInteger)
+        );
+        assert_eq!(
+            Signature::parse_der(&negative_r),
+            Err(DerError::NegativeInteger)
+        );
+        assert_eq!(
+            Signature::parse_der(&padded_r),
+            Err(DerError::ExcessivePadding)
+        );
+        assert_eq!(
+            Signature::parse_der(&negative_s),
+            Err(DerError::NegativeInteger)
+        );
+        assert_eq!(
+            Signature::parse_der(&padded_s),
+            Err(DerError::ExcessivePadding)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::errors::TxError;
+use crate::helpers::{encode_varint, read_bytes, read_varint, Hash256};
+
+/// A legacy (non-segwit) Bitcoin transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tx {
+    pub version: u32,
+    pub tx_ins: Vec<TxIn>,
+    pub tx_outs: Vec<TxOut>,
+    pub locktime: u32,
+}
+
+/// Input spending output `prev_index` of the transaction `prev_tx`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxIn {
+    pub prev_tx: Hash256,
+    pub prev_index: u32,
+    pub script_sig: Vec<u8>,
+    pub sequence: u32,
+}
+
+/// Output locking `amount` satoshis with `script_pubkey`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxOut {
+    pub amount: u64,
+    pub script_pubkey: Vec<u8>,
+}
+
+impl Tx {
+    pub fn new(version: u32, tx_ins: Vec<TxIn>, tx_outs: Vec<TxOut>, locktime: u32) -> Self {
+        Self {
+            version,
+            tx_ins,
+            tx_outs,
+            locktime,
+        }
+    }
+
+    /// Parses a serialized transaction, which must span all of `bytes`.
+    pub fn parse(bytes: &[u8]) -> Result<Self, TxError> {
+        let mut stream = bytes;
+        let version = read_u32(&mut stream)?;
+        if let [0x00, 0x01, ..] = stream {
+            return Err(TxError::SegwitNotSupported);
+        }
+        let tx_ins = (0..read_varint(&mut stream)?)
+            .map(|_| TxIn::parse(&mut stream))
+            .collect::<Result<_, _>>()?;
+        let tx_outs = (0..read_varint(&mut stream)?)
+            .map(|_| TxOut::parse(&mut stream))
+            .collect::<Result<_, _>>()?;
+        let locktime = read_u32(&mut stream)?;
+        if !stream.is_empty() {
+            return Err(TxError::TrailingBytes(stream.len()));
+        }
+        Ok(Self::new(version, tx_ins, tx_outs, locktime))
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut result = self.version.to_le_bytes().to_vec();
+        result.extend(encode_varint(self.tx_ins.len() as u64));
+        for tx_in in &self.tx_ins {
+            result.extend(tx_in.serialize());
+        }
+        result.extend(encode_varint(self.tx_outs.len() as u64));
+        for tx_out in &self.tx_outs {
+            result.extend(tx_out.serialize());
+        }
+        result.extend(self.locktime.to_le_bytes());
+        result
+    }
+
+    /// hash256 of the serialized transaction.
+    pub fn hash(&self) -> Hash256 {
+        Hash256::of(&self.serialize())
+    }
+
+    /// Transaction id as displayed by block explorers.
+    pub fn id(&self) -> String {
+        self.hash().to_string()
+    }
+}
+
+impl TxIn {
+    pub fn new(prev_tx: Hash256, prev_index: u32, script_sig: Vec<u8>, sequence: u32) -> Self {
+        Self {
+            prev_tx,
+            prev_index,
+            script_sig,
+            sequence,
+        }
+    }
+
+    fn parse(stream: &mut &[u8]) -> Result<Self, TxError> {
+        let prev_tx = Hash256::new(read_bytes(stream, 32)?.try_into().unwrap());
+        let prev_index = read_u32(stream)?;
+        let script_sig = read_script(stream)?;
+        let sequence = read_u32(stream)?;
+        Ok(Self::new(prev_tx, prev_index, script_sig, sequence))
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut result = self.prev_tx.as_bytes().to_vec();
+        result.extend(self.prev_index.to_le_bytes());
+        result.extend(encode_varint(self.script_sig.len() as u64));
+        result.extend(&self.script_sig);
+        result.extend(self.sequence.to_le_bytes());
+        result
+    }
+}
+
+impl TxOut {
+    pub fn new(amount: u64, script_pubkey: Vec<u8>) -> Self {
+        Self {
+            amount,
+            script_pubkey,
+        }
+    }
+
+    fn parse(stream: &mut &[u8]) -> Result<Self, TxError> {
+        let amount = u64::from_le_bytes(read_bytes(stream, 8)?.try_into().unwrap());
+        let script_pubkey = read_script(stream)?;
+        Ok(Self::new(amount, script_pubkey))
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut result = self.amount.to_le_bytes().to_vec();
+        result.extend(encode_varint(self.script_pubkey.len() as u64));
+        result.extend(&self.script_pubkey);
+        result
+    }
+}
+
+fn read_u32(stream: &mut &[u8]) -> Result<u32, TxError> {
+    Ok(u32::from_le_bytes(
+        read_bytes(stream, 4)?.try_into().unwrap(),
+    ))
+}
+
+fn read_script(stream: &mut &[u8]) -> Result<Vec<u8>, TxError> {
+    let length = usize::try_from(read_varint(stream)?).map_err(|_| TxError::UnexpectedEnd)?;
+    Ok(read_bytes(stream, length)?.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Mainnet transaction 452c629d67e41baec3ac6f04fe744b4b9617f8f859c63b3002f8684e7a4fee03.
+    const BOOK_TX: &str = "0100000001813f79011acb80925dfe69b3def355fe914bd1d96a3f5f71bf8303c6a989c7d1000000006b483045022100ed81ff192e75a3fd2304004dcadb746fa5e24c5031ccfcf21320b0277457c98f02207a986d955c6e0cb35d446a89d3f56100f4d7f67801c31967743a9c8e10615bed01210349fc4e631e3624a545de3f89f5d8684c7b8138bd94bdd531d2e213bf016b278afeffffff02a135ef01000000001976a914bc3b654dca7e56b04dca18f2566cdaf02e8d9ada88ac99c39800000000001976a9141c4bc762dd5423e332166702cb75f40df79fea1288ac19430600";
+
+    /// Coinbase transaction of the genesis block,
+    /// 4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b.
+    const GENESIS_COINBASE_TX: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+
+    #[test]
+    fn parse_a_mainnet_transaction() {
+        let tx = Tx::parse(&hex::decode(BOOK_TX).unwrap()).unwrap();
+
+        assert_eq!(tx.version, 1);
+        assert_eq!(tx.tx_ins.len(), 1);
+        assert_eq!(
+            hex::encode(tx.tx_ins[0].prev_tx.as_bytes()),
+            "813f79011acb80925dfe69b3def355fe914bd1d96a3f5f71bf8303c6a989c7d1"
+        );
+        assert_eq!(
+            tx.tx_ins[0].prev_tx.to_string(),
+            "d1c789a9c60383bf715f3f6ad9d14b91fe55f3deb369fe5d9280cb1a01793f81"
+        );
+        assert_eq!(tx.tx_ins[0].prev_index, 0);
+        assert_eq!(tx.tx_ins[0].script_sig.len(), 0x6b);
+        assert_eq!(tx.tx_ins[0].sequence, 0xfffffffe);
+        assert_eq!(tx.tx_outs.len(), 2);
+        assert_eq!(tx.tx_outs[0].amount, 32454049);
+        assert_eq!(
+            hex::encode(&tx.tx_outs[0].script_pubkey),
+            "76a914bc3b654dca7e56b04dca18f2566cdaf02e8d9ada88ac"
+        );
+        assert_eq!(tx.tx_outs[1].amount, 10011545);
+        assert_eq!(tx.locktime, 410393);
+    }
+
+    #[test]
+    fn mainnet_transactions_round_trip() {
+        let fixtures = [
+            (
+                BOOK_TX,
+                "452c629d67e41baec3ac6f04fe744b4b9617f8f859c63b3002f8684e7a4fee03",
+            ),
+            (
+                GENESIS_COINBASE_TX,
+                "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+            ),
+        ];
+        for (raw, id) in fixtures {
+            let raw = hex::decode(raw).unwrap();
+            let tx = Tx::parse(&raw).unwrap();
+
+            assert_eq!(tx.serialize(), raw);
+            assert_eq!(tx.id(), id);
+        }
+    }
+
+    #[test]
+    fn reject_malformed_transactions() {
+        let raw = hex::decode(BOOK_TX).unwrap();
+        let with_trailing_byte = [raw.clone(), vec![0x00]].concat();
+        let segwit = hex::decode("020000000001").unwrap();
+        let huge_script = [&raw[..41], &[0xff; 9]].concat();
+        let padded_input_count = [&raw[..4], &[0xfd, 0x01, 0x00], &raw[5..]].concat();
+
+        assert_eq!(Tx::parse(&[]), Err(TxError::UnexpectedEnd));
+        assert_eq!(
+            Tx::parse(&raw[..raw.len() - 1]),
+            Err(TxError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Tx::parse(&with_trailing_byte),
+            Err(TxError::TrailingBytes(1))
+        );
+        assert_eq!(Tx::parse(&segwit), Err(TxError::SegwitNotSupported));
+        assert_eq!(Tx::parse(&huge_script), Err(TxError::UnexpectedEnd));
+        assert_eq!(
+            Tx::parse(&padded_input_count),
+            Err(TxError::NonCanonicalVarint(1))
+        );
+    }
+
+    #[test]
+    fn many_outputs_use_a_multi_byte_count() {
+        let tx_in = TxIn::new(Hash256::new([0x11; 32]), 3, vec![0x51], 0xffffffff);
+        let tx_outs = (0..300).map(|i| TxOut::new(i, vec![0x6a; 2])).collect();
+        let tx = Tx::new(2, vec![tx_in], tx_outs, 0);
+        let raw = tx.serialize();
+
+        assert_eq!(raw[4 + 1 + 32 + 4 + 2 + 4..][..3], [0xfd, 0x2c, 0x01]);
+        assert_eq!(Tx::parse(&raw), Ok(tx));
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_parse_round_trip(
+            version in any::<u32>(),
+            scripts in proptest::collection::vec(proptest::collection::vec(any::<u8>(), 0..300), 1..4),
+            amount in any::<u64>(),
+            locktime in any::<u32>(),
+        ) {
+            let tx_ins = scripts
+                .iter()
+                .enumerate()
+                .map(|(i, script)| TxIn::new(Hash256::new([i as u8; 32]), i as u32, script.clone(), 0xffffffff))
+                .collect();
+            let tx_outs = scripts
+                .iter()
+                .map(|script| TxOut::new(amount, script.clone()))
+                .collect();
+            let tx = Tx::new(version, tx_ins, tx_outs, locktime);
+
+            prop_assert_eq!(Tx::parse(&tx.serialize()), Ok(tx));
+        }
+    }
+}
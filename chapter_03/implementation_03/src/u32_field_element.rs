@@ -0,0 +1,162 @@
+use crate::field::{byte_length, to_padded_bytes, Field};
+use num_bigint::BigUint;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Port of the `u32` field element from chapter 1, with products computed in
+/// `u64` so any prime below 2^32 can be used.
+///
+/// `src/chapter_01/implementation_01/main.rs` is a standalone binary with no
+/// manifest, so it can not be depended on; this module is the library home
+/// of that type and is where it implements `Field`.
+#[derive(Debug, Clone, Copy)]
+pub struct U32FieldElement {
+    num: u32,
+    prime: u32,
+}
+
+impl U32FieldElement {
+    pub fn new(num: u32, prime: u32) -> Self {
+        if num >= prime {
+            panic!("Num {} not in field range 0 to {}", num, prime);
+        }
+
+        Self { num, prime }
+    }
+
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    pub fn prime(&self) -> u32 {
+        self.prime
+    }
+
+    pub fn pow(self, power: i32) -> Self {
+        let exponent = (power as i64).rem_euclid(self.prime as i64 - 1);
+        Field::pow(&self, &BigUint::from(exponent as u64))
+    }
+}
+
+impl Add for U32FieldElement {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.prime != other.prime {
+            panic!("Cannot add two numbers in different field");
+        }
+        let num = ((self.num as u64 + other.num as u64) % self.prime as u64) as u32;
+        Self::new(num, self.prime)
+    }
+}
+
+impl Sub for U32FieldElement {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        if self.prime != other.prime {
+            panic!("Cannot subtract two numbers in different field");
+        }
+        let num =
+            ((self.num as u64 + self.prime as u64 - other.num as u64) % self.prime as u64) as u32;
+        Self::new(num, self.prime)
+    }
+}
+
+impl Mul for U32FieldElement {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if self.prime != other.prime {
+            panic!("Cannot multiplicate two numbers in different field");
+        }
+        let num = ((self.num as u64 * other.num as u64) % self.prime as u64) as u32;
+        Self::new(num, self.prime)
+    }
+}
+
+impl Div for U32FieldElement {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if self.prime != other.prime {
+            panic!("Cannot divide two numbers of different Fields");
+        }
+
+        match other.inverse() {
+            Some(other_inverse) => self * other_inverse,
+            None => panic!("Cannot divide by zero-valued FieldElement!"),
+        }
+    }
+}
+
+impl PartialEq for U32FieldElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.num == other.num && self.prime == other.prime
+    }
+}
+
+impl fmt::Display for U32FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "number: {}, prime: {}", self.num, self.prime)
+    }
+}
+
+impl Field for U32FieldElement {
+    fn zero(&self) -> Self {
+        Self::new(0, self.prime)
+    }
+
+    fn one(&self) -> Self {
+        Self::new(1, self.prime)
+    }
+
+    fn modulus(&self) -> BigUint {
+        BigUint::from(self.prime)
+    }
+
+    fn element_from_bytes(&self, bytes: &[u8]) -> Option<Self> {
+        let num = u32::try_from(BigUint::from_bytes_be(bytes)).ok()?;
+        (num < self.prime).then(|| Self::new(num, self.prime))
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        to_padded_bytes(&BigUint::from(self.num), byte_length(&self.modulus()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_from_chapter_one() {
+        assert_eq!(
+            U32FieldElement::new(2, 7) / U32FieldElement::new(3, 7),
+            U32FieldElement::new(3, 7)
+        );
+        assert_eq!(
+            U32FieldElement::new(2, 7).pow(-1),
+            U32FieldElement::new(4, 7)
+        );
+        assert_eq!(
+            U32FieldElement::new(1, 7) - U32FieldElement::new(3, 7),
+            U32FieldElement::new(5, 7)
+        );
+    }
+
+    #[test]
+    fn large_primes_do_not_overflow() {
+        let prime = 4_294_967_291;
+        let a = U32FieldElement::new(prime - 1, prime);
+
+        assert_eq!(a * a, U32FieldElement::new(1, prime));
+        assert_eq!(a + a, U32FieldElement::new(prime - 2, prime));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero_panics() {
+        let _ = U32FieldElement::new(2, 7) / U32FieldElement::new(0, 7);
+    }
+}